@@ -259,6 +259,8 @@ pub(crate) mod test {
             typo_tolerance: Setting::NotSet,
             faceting: Setting::NotSet,
             pagination: Setting::NotSet,
+            max_token_length: Setting::NotSet,
+            max_document_id_length: Setting::NotSet,
             _kind: std::marker::PhantomData,
         };
         settings.check()
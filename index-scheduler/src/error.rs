@@ -50,6 +50,8 @@ pub enum Error {
     IndexNotFound(String),
     #[error("Index `{0}` already exists.")]
     IndexAlreadyExists(String),
+    #[error("The maximum number of indexes ({max}) is reached. No more indexes can be created.")]
+    IndexLimitReached { max: usize },
     #[error(
         "Indexes must be declared only once during a swap. `{0}` was specified several times."
     )]
@@ -139,6 +141,7 @@ impl ErrorCode for Error {
         match self {
             Error::IndexNotFound(_) => Code::IndexNotFound,
             Error::IndexAlreadyExists(_) => Code::IndexAlreadyExists,
+            Error::IndexLimitReached { .. } => Code::IndexLimitReached,
             Error::SwapDuplicateIndexesFound(_) => Code::InvalidSwapDuplicateIndexFound,
             Error::SwapDuplicateIndexFound(_) => Code::InvalidSwapDuplicateIndexFound,
             Error::SwapIndexNotFound(_) => Code::IndexNotFound,
@@ -1,17 +1,19 @@
 /// the map size to use when we don't succeed in reading it in indexes.
 const DEFAULT_MAP_SIZE: usize = 10 * 1024 * 1024 * 1024; // 10 GiB
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 
+use arc_swap::ArcSwap;
 use meilisearch_types::heed::{EnvClosingEvent, EnvOpenOptions};
 use meilisearch_types::milli::Index;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
 use super::IndexStatus::{self, Available, BeingDeleted, Closing, Missing};
-use crate::lru::{InsertionOutcome, LruMap};
+use crate::lru::{InsertionOutcome, LruHandle, LruMap};
 use crate::{clamp_to_page_size, Result};
 
 /// Keep an internally consistent view of the open indexes in memory.
@@ -28,6 +30,10 @@ use crate::{clamp_to_page_size, Result};
 pub struct IndexMap {
     /// A LRU map of indexes that are in the open state and available for queries.
     available: LruMap<Uuid, Index>,
+    /// A copy of the available indexes that can be read without taking any lock on the map.
+    ///
+    /// It is replaced every time an index enters or leaves the available indexes.
+    published: Arc<ArcSwap<AvailableIndexes>>,
     /// A map of indexes that are not available for queries, either because they are being deleted
     /// or because they are being closed.
     ///
@@ -49,6 +55,9 @@ pub struct IndexMap {
     generation: usize,
 }
 
+/// The indexes available for queries, along with the handles marking them as used in the LRU map.
+pub type AvailableIndexes = HashMap<Uuid, (Index, LruHandle)>;
+
 #[derive(Clone)]
 pub struct ClosingIndex {
     uuid: Uuid,
@@ -133,7 +142,35 @@ impl ReopenableIndex {
 
 impl IndexMap {
     pub fn new(cap: usize) -> IndexMap {
-        Self { unavailable: Default::default(), available: LruMap::new(cap), generation: 0 }
+        Self {
+            unavailable: Default::default(),
+            available: LruMap::new(cap),
+            published: Default::default(),
+            generation: 0,
+        }
+    }
+
+    /// Returns the copy of the available indexes that is kept up to date by the map.
+    ///
+    /// Reading it doesn't require any lock, an index that is not in it must be retrieved through [`Self::get`].
+    pub fn published(&self) -> Arc<ArcSwap<AvailableIndexes>> {
+        self.published.clone()
+    }
+
+    fn publish_available(&self, uuid: &Uuid, index: &Index) {
+        if let Some(handle) = self.available.handle(uuid) {
+            let mut published = AvailableIndexes::clone(&self.published.load());
+            published.insert(*uuid, (index.clone(), handle));
+            self.published.store(Arc::new(published));
+        }
+    }
+
+    fn publish_unavailable(&self, uuid: &Uuid) {
+        if self.published.load().contains_key(uuid) {
+            let mut published = AvailableIndexes::clone(&self.published.load());
+            published.remove(uuid);
+            self.published.store(Arc::new(published));
+        }
     }
 
     /// Gets the current status of an index in the map.
@@ -185,6 +222,7 @@ impl IndexMap {
                 panic!("Attempt to open an index that was already opened")
             }
         }
+        self.publish_available(uuid, &index);
         Ok(index)
     }
 
@@ -218,6 +256,8 @@ impl IndexMap {
     }
 
     fn close(&mut self, uuid: Uuid, index: Index, map_size_growth: usize) {
+        // the published copy would otherwise prevent the environment from closing.
+        self.publish_unavailable(&uuid);
         let map_size = index.map_size().unwrap_or(DEFAULT_MAP_SIZE) + map_size_growth;
         let closing_event = index.prepare_for_closing();
         let generation = self.next_generation();
@@ -242,6 +282,7 @@ impl IndexMap {
         uuid: &Uuid,
     ) -> std::result::Result<Option<EnvClosingEvent>, Option<ClosingIndex>> {
         if let Some(index) = self.available.remove(uuid) {
+            self.publish_unavailable(uuid);
             self.unavailable.insert(*uuid, None);
             return Ok(Some(index.prepare_for_closing()));
         }
@@ -299,6 +340,8 @@ fn create_or_open_index(
 #[cfg(test)]
 mod tests {
 
+    use std::time::Duration;
+
     use meilisearch_types::heed::Env;
     use meilisearch_types::Index;
     use uuid::Uuid;
@@ -362,6 +405,40 @@ mod tests {
         assert_index_size(index, mapper.index_base_map_size + mapper.index_growth_amount * 2);
     }
 
+    #[test]
+    fn open_index_concurrently() {
+        let (mapper, env, _handle) = IndexMapper::test();
+        let index = mapper.create_index(env.write_txn().unwrap(), "index", None).unwrap();
+        let index_path = index.path().to_owned();
+        drop(index);
+
+        // The readers must not need the lock on the index map to get an opened index.
+        let index_map = mapper.index_map.write().unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        for _ in 0..16 {
+            let (mapper, env, sender) = (mapper.clone(), env.clone(), sender.clone());
+            std::thread::spawn(move || {
+                let rtxn = env.read_txn().unwrap();
+                let paths: Vec<_> = (0..1000)
+                    .map(|_| mapper.index(&rtxn, "index").unwrap().path().to_owned())
+                    .collect();
+                sender.send(paths).unwrap();
+            });
+        }
+        drop(sender);
+
+        for _ in 0..16 {
+            let paths = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+            assert!(paths.iter().all(|path| path == &index_path));
+        }
+        drop(index_map);
+
+        // The index is still closed when it is evicted although it was read concurrently.
+        mapper.resize_index(&env.read_txn().unwrap(), "index").unwrap();
+        let index = mapper.create_index(env.write_txn().unwrap(), "index", None).unwrap();
+        assert_index_size(index, mapper.index_base_map_size + mapper.index_growth_amount);
+    }
+
     fn assert_index_size(index: Index, expected: usize) {
         let expected = clamp_to_page_size(expected);
         let index_map_size = index.map_size().unwrap();
@@ -1,8 +1,10 @@
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use std::{fs, thread};
 
+use arc_swap::ArcSwap;
 use log::error;
 use meilisearch_types::heed::types::{SerdeJson, Str};
 use meilisearch_types::heed::{Database, Env, RoTxn, RwTxn};
@@ -12,7 +14,7 @@ use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use self::index_map::IndexMap;
+use self::index_map::{AvailableIndexes, IndexMap};
 use self::IndexStatus::{Available, BeingDeleted, Closing, Missing};
 use crate::uuid_codec::UuidCodec;
 use crate::{Error, Result};
@@ -51,6 +53,8 @@ const INDEX_STATS: &str = "index-stats";
 pub struct IndexMapper {
     /// Keep track of the opened indexes. Used mainly by the index resolver.
     index_map: Arc<RwLock<IndexMap>>,
+    /// The opened indexes as published by the `index_map`, read without taking its lock.
+    available_indexes: Arc<ArcSwap<AvailableIndexes>>,
 
     /// Map an index name with an index uuid currently available on disk.
     pub(crate) index_mapping: Database<Str, UuidCodec>,
@@ -61,11 +65,13 @@ pub struct IndexMapper {
     pub(crate) index_stats: Database<UuidCodec, SerdeJson<IndexStats>>,
 
     /// Path to the folder where the LMDB environments of each index are.
-    base_path: PathBuf,
+    pub(crate) base_path: PathBuf,
     /// The map size an index is opened with on the first time.
     index_base_map_size: usize,
     /// The quantity by which the map size of an index is incremented upon reopening, in bytes.
     index_growth_amount: usize,
+    /// The maximum number of indexes that can be created, `None` if unlimited.
+    pub(crate) max_indexes: Option<usize>,
     pub indexer_config: Arc<IndexerConfig>,
 }
 
@@ -116,6 +122,20 @@ impl IndexStats {
     }
 }
 
+/// What was fixed by [`IndexMapper::repair`] to make the index mapping consistent with the disk.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RepairReport {
+    /// Names that were mapped to an index whose data was missing on disk.
+    /// They have been removed from the index mapping.
+    pub dangling_names: Vec<String>,
+    /// Index folders that were present on disk but that no name was mapped to.
+    /// They have been removed from the disk.
+    pub orphaned_indexes: Vec<Uuid>,
+    /// Orphaned index folders that couldn't be removed from the disk, the errors are logged.
+    /// They are not referenced by the mapping anymore and are retried by the next repair.
+    pub unremoved_indexes: Vec<Uuid>,
+}
+
 impl IndexMapper {
     pub fn new(
         env: &Env,
@@ -123,15 +143,20 @@ impl IndexMapper {
         index_base_map_size: usize,
         index_growth_amount: usize,
         index_count: usize,
+        max_indexes: Option<usize>,
         indexer_config: IndexerConfig,
     ) -> Result<Self> {
+        let index_map = IndexMap::new(index_count);
+        let available_indexes = index_map.published();
         Ok(Self {
-            index_map: Arc::new(RwLock::new(IndexMap::new(index_count))),
+            index_map: Arc::new(RwLock::new(index_map)),
+            available_indexes,
             index_mapping: env.create_database(Some(INDEX_MAPPING))?,
             index_stats: env.create_database(Some(INDEX_STATS))?,
             base_path,
             index_base_map_size,
             index_growth_amount,
+            max_indexes,
             indexer_config: Arc::new(indexer_config),
         })
     }
@@ -148,29 +173,67 @@ impl IndexMapper {
                 wtxn.commit()?;
                 Ok(index)
             }
-            Err(Error::IndexNotFound(_)) => {
-                let uuid = Uuid::new_v4();
-                self.index_mapping.put(&mut wtxn, name, &uuid)?;
-
-                let index_path = self.base_path.join(uuid.to_string());
-                fs::create_dir_all(&index_path)?;
-
-                // Error if the UUIDv4 somehow already exists in the map, since it should be fresh.
-                // This is very unlikely to happen in practice.
-                // TODO: it would be better to lazily create the index. But we need an Index::open function for milli.
-                let index = self.index_map.write().unwrap().create(
-                    &uuid,
-                    &index_path,
-                    date,
-                    self.index_base_map_size,
-                )?;
+            Err(Error::IndexNotFound(_)) => self.create_index_with(wtxn, name, date, |_| Ok(())),
+            error => error,
+        }
+    }
 
-                wtxn.commit()?;
+    /// Creates the index and initializes it before registering its name in the mapping.
+    ///
+    /// The index can't be seen until it is initialized. If the initialization fails,
+    /// the index is removed from the disk and the mapping is left untouched.
+    ///
+    /// Fails with [`Error::IndexLimitReached`] if the maximum number of indexes already exists.
+    pub fn create_index_with(
+        &self,
+        mut wtxn: RwTxn,
+        name: &str,
+        date: Option<(OffsetDateTime, OffsetDateTime)>,
+        init: impl FnOnce(&Index) -> Result<()>,
+    ) -> Result<Index> {
+        if self.index_exists(&wtxn, name)? {
+            return Err(Error::IndexAlreadyExists(name.to_string()));
+        }
+        if let Some(max) = self.max_indexes {
+            if self.index_mapping.len(&wtxn)? as usize >= max {
+                return Err(Error::IndexLimitReached { max });
+            }
+        }
 
-                Ok(index)
+        let uuid = Uuid::new_v4();
+        self.index_mapping.put(&mut wtxn, name, &uuid)?;
+
+        let index_path = self.base_path.join(uuid.to_string());
+        fs::create_dir_all(&index_path)?;
+
+        // Error if the UUIDv4 somehow already exists in the map, since it should be fresh.
+        // This is very unlikely to happen in practice.
+        // TODO: it would be better to lazily create the index. But we need an Index::open function for milli.
+        let index = self.index_map.write().unwrap().create(
+            &uuid,
+            &index_path,
+            date,
+            self.index_base_map_size,
+        )?;
+
+        if let Err(error) = init(&index) {
+            // Nobody else knows the uuid of the index, we can close it and remove it right away.
+            drop(wtxn);
+            drop(index);
+            let closing_event = self.index_map.write().unwrap().start_deletion(&uuid);
+            if let Ok(Some(closing_event)) = closing_event {
+                closing_event.wait();
             }
-            error => error,
+            if let Err(e) = fs::remove_dir_all(&index_path) {
+                error!("An error happened when removing the index {} ({}): {}", name, uuid, e);
+            }
+            self.index_map.write().unwrap().end_deletion(&uuid);
+            return Err(error);
         }
+
+        wtxn.commit()?;
+
+        Ok(index)
     }
 
     /// Removes the index from the mapping table and the in-memory index map
@@ -254,6 +317,67 @@ impl IndexMapper {
         Ok(())
     }
 
+    /// Reconciles the index mapping with the index folders present on disk.
+    ///
+    /// This recovers from a crash that happened in the middle of an index creation or deletion:
+    /// - the names pointing to an index folder that doesn't exist are removed from the mapping,
+    /// - the index folders that no name points to are removed from the disk.
+    ///
+    /// The indexes that are currently opened, closing or being deleted are never touched.
+    pub fn repair(&self, mut wtxn: RwTxn) -> Result<RepairReport> {
+        let mut report = RepairReport::default();
+
+        let mut orphaned_indexes = Vec::new();
+        let mut mapped_uuids = HashSet::new();
+        let mut dangling = Vec::new();
+        for result in self.index_mapping.iter(&wtxn)? {
+            let (name, uuid) = result?;
+            if self.base_path.join(uuid.to_string()).exists() {
+                mapped_uuids.insert(uuid);
+            } else {
+                dangling.push((name.to_string(), uuid));
+            }
+        }
+
+        for (name, uuid) in dangling {
+            self.index_mapping.delete(&mut wtxn, &name)?;
+            self.index_stats.delete(&mut wtxn, &uuid)?;
+            report.dangling_names.push(name);
+        }
+
+        {
+            let index_map = self.index_map.read().unwrap();
+            for entry in fs::read_dir(&self.base_path)? {
+                let entry = entry?;
+                let uuid = match entry.file_name().to_str().map(Uuid::parse_str) {
+                    Some(Ok(uuid)) => uuid,
+                    // This folder is not an index, we don't know what it is and leave it as is.
+                    _ => continue,
+                };
+                if !mapped_uuids.contains(&uuid) && matches!(index_map.get(&uuid), Missing) {
+                    orphaned_indexes.push(uuid);
+                }
+            }
+        }
+        orphaned_indexes.sort_unstable();
+
+        // The mapping must not reference the orphaned folders anymore before we remove them.
+        wtxn.commit()?;
+
+        // The mapping is already repaired, a folder we fail to remove must not hide the others.
+        for uuid in orphaned_indexes {
+            match fs::remove_dir_all(self.base_path.join(uuid.to_string())) {
+                Ok(()) => report.orphaned_indexes.push(uuid),
+                Err(e) => {
+                    error!("An error happened when removing the orphaned index {}: {}", uuid, e);
+                    report.unremoved_indexes.push(uuid);
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     pub fn exists(&self, rtxn: &RoTxn, name: &str) -> Result<bool> {
         Ok(self.index_mapping.get(rtxn, name)?.is_some())
     }
@@ -285,6 +409,13 @@ impl IndexMapper {
             .get(rtxn, name)?
             .ok_or_else(|| Error::IndexNotFound(name.to_string()))?;
 
+        // the opened indexes are retrieved without taking the lock on the index map,
+        // this way the searches on the same index don't contend with each other.
+        if let Some((index, handle)) = self.available_indexes.load().get(&uuid) {
+            handle.touch();
+            return Ok(index.clone());
+        }
+
         let mut tries = 0;
         // attempts to open the index in a loop.
         //
@@ -42,12 +42,14 @@ use std::time::Duration;
 use dump::{KindDump, TaskDump, UpdateFile};
 pub use error::Error;
 use file_store::FileStore;
+pub use index_mapper::RepairReport;
 use meilisearch_types::error::ResponseError;
 use meilisearch_types::heed::types::{OwnedType, SerdeBincode, SerdeJson, Str};
 use meilisearch_types::heed::{self, Database, Env, RoTxn, RwTxn};
 use meilisearch_types::milli::documents::DocumentsBatchBuilder;
 use meilisearch_types::milli::update::IndexerConfig;
 use meilisearch_types::milli::{self, CboRoaringBitmapCodec, Index, RoaringBitmapCodec, BEU32};
+use meilisearch_types::settings::{apply_settings_to_builder, Checked, Settings};
 use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task};
 use roaring::RoaringBitmap;
 use synchronoise::SignalEvent;
@@ -236,11 +238,18 @@ pub struct IndexSchedulerOptions {
     pub index_growth_amount: usize,
     /// The number of indexes that can be concurrently opened in memory.
     pub index_count: usize,
+    /// The maximum number of indexes that can be created, `None` if unlimited.
+    ///
+    /// The indexes already existing beyond this limit stay usable.
+    pub max_indexes: Option<usize>,
     /// Configuration used during indexing for each meilisearch index.
     pub indexer_config: IndexerConfig,
     /// Set to `true` iff the index scheduler is allowed to automatically
     /// batch tasks together, to process multiple tasks at once.
     pub autobatching_enabled: bool,
+    /// Set to `true` to reconcile the index mapping with the indexes present on disk
+    /// before starting the run loop, see [`IndexScheduler::repair_indexes`].
+    pub repair_indexes: bool,
 }
 
 /// Structure which holds meilisearch's indexes and schedules the tasks
@@ -360,6 +369,7 @@ impl IndexScheduler {
         #[cfg(test)] test_breakpoint_sdr: crossbeam::channel::Sender<(Breakpoint, bool)>,
         #[cfg(test)] planned_failures: Vec<(usize, tests::FailureLocation)>,
     ) -> Result<Self> {
+        let repair_indexes = options.repair_indexes;
         std::fs::create_dir_all(&options.tasks_path)?;
         std::fs::create_dir_all(&options.update_file_path)?;
         std::fs::create_dir_all(&options.indexes_path)?;
@@ -406,6 +416,7 @@ impl IndexScheduler {
                 budget.map_size,
                 options.index_growth_amount,
                 budget.index_count,
+                options.max_indexes,
                 options.indexer_config,
             )?,
             env,
@@ -425,6 +436,13 @@ impl IndexScheduler {
             run_loop_iteration: Arc::new(RwLock::new(0)),
         };
 
+        if repair_indexes {
+            let report = this.repair_indexes()?;
+            if report != RepairReport::default() {
+                log::warn!("Repaired the index mapping: {:?}", report);
+            }
+        }
+
         this.run();
         Ok(this)
     }
@@ -905,6 +923,46 @@ impl IndexScheduler {
         Ok(index)
     }
 
+    /// Create a new index with its primary key and settings, without any associated task.
+    ///
+    /// The settings are applied before the index is registered: the index is never seen
+    /// without them and, if they can't be applied, the index isn't created at all.
+    pub fn create_raw_index_with_settings(
+        &self,
+        name: &str,
+        date: Option<(OffsetDateTime, OffsetDateTime)>,
+        primary_key: Option<&str>,
+        settings: &Settings<Checked>,
+    ) -> Result<Index> {
+        let wtxn = self.env.write_txn()?;
+        self.index_mapper.create_index_with(wtxn, name, date, |index| {
+            let mut index_wtxn = index.write_txn()?;
+            let indexer_config = self.index_mapper.indexer_config();
+            let mut builder = milli::update::Settings::new(&mut index_wtxn, index, indexer_config);
+            if let Some(primary_key) = primary_key {
+                builder.set_primary_key(primary_key.to_string());
+            }
+            apply_settings_to_builder(settings, &mut builder);
+            builder
+                .execute(|indexing_step| log::debug!("update: {:?}", indexing_step), || false)?;
+            index_wtxn.commit()?;
+            Ok(())
+        })
+    }
+
+    /// Reconciles the mapping between the index names and the indexes present on disk.
+    ///
+    /// See [`RepairReport`] for what can be fixed.
+    ///
+    /// It is called when the index scheduler is created with [`IndexSchedulerOptions::repair_indexes`].
+    /// It can also be called while batches are being processed: the index mapping is read under a
+    /// write transaction, so it waits for the index creations and deletions in progress, and the
+    /// indexes that are opened or being deleted are never considered orphaned.
+    pub fn repair_indexes(&self) -> Result<RepairReport> {
+        let wtxn = self.env.write_txn()?;
+        self.index_mapper.repair(wtxn)
+    }
+
     /// Create a file and register it in the index scheduler.
     ///
     /// The returned file and uuid can be used to associate
@@ -1351,6 +1409,7 @@ mod tests {
     use meilisearch_types::milli::update::IndexDocumentsMethod::{
         ReplaceDocuments, UpdateDocuments,
     };
+    use meilisearch_types::settings::{RankingRuleView, Setting, TypoSettings, Unchecked};
     use meilisearch_types::tasks::IndexSwap;
     use meilisearch_types::VERSION_FILE_NAME;
     use tempfile::{NamedTempFile, TempDir};
@@ -1394,8 +1453,10 @@ mod tests {
                 index_base_map_size: 1000 * 1000, // 1 MB, we don't use MiB on purpose.
                 index_growth_amount: 1000 * 1000, // 1 MB
                 index_count: 5,
+                max_indexes: None,
                 indexer_config,
                 autobatching_enabled,
+                repair_indexes: false,
             };
 
             let index_scheduler = Self::new(options, sender, planned_failures).unwrap();
@@ -3810,4 +3871,145 @@ mod tests {
         // No matter what happens in process_batch, the index_scheduler should be internally consistent
         snapshot!(snapshot_index_scheduler(&index_scheduler), name: "index_creation_failed");
     }
+
+    #[test]
+    fn repair_indexes() {
+        let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
+
+        index_scheduler.register(index_creation_task("catto", "mouse")).unwrap();
+        handle.advance_one_successful_batch();
+
+        // We simulate a crash that left a name pointing to an index that doesn't exist on disk...
+        let mut wtxn = index_scheduler.env.write_txn().unwrap();
+        index_scheduler
+            .index_mapper
+            .index_mapping
+            .put(&mut wtxn, "doggo", &Uuid::new_v4())
+            .unwrap();
+        wtxn.commit().unwrap();
+        // ...and an index on disk that no name points to.
+        let orphan = Uuid::new_v4();
+        let orphan_path = index_scheduler.index_mapper.base_path.join(orphan.to_string());
+        std::fs::create_dir_all(&orphan_path).unwrap();
+
+        let report = index_scheduler.repair_indexes().unwrap();
+        assert_eq!(report.dangling_names, vec![S("doggo")]);
+        assert_eq!(report.orphaned_indexes, vec![orphan]);
+        assert!(!orphan_path.exists());
+
+        // The mapping only references the real index and it is still usable.
+        assert_eq!(index_scheduler.index_names().unwrap(), vec![S("catto")]);
+        index_scheduler.index("catto").unwrap();
+
+        // There is nothing left to repair.
+        let report = index_scheduler.repair_indexes().unwrap();
+        assert_eq!(report, RepairReport::default());
+    }
+
+    #[test]
+    fn repair_indexes_keeps_going_when_a_removal_fails() {
+        let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+
+        // A file can't be removed as an index folder.
+        let unremovable = Uuid::new_v4();
+        let unremovable_path = index_scheduler.index_mapper.base_path.join(unremovable.to_string());
+        std::fs::write(&unremovable_path, b"doggo").unwrap();
+        let orphan = Uuid::new_v4();
+        let orphan_path = index_scheduler.index_mapper.base_path.join(orphan.to_string());
+        std::fs::create_dir_all(&orphan_path).unwrap();
+
+        let report = index_scheduler.repair_indexes().unwrap();
+        assert_eq!(report.orphaned_indexes, vec![orphan]);
+        assert_eq!(report.unremoved_indexes, vec![unremovable]);
+        assert!(!orphan_path.exists());
+        assert!(unremovable_path.exists());
+    }
+
+    #[test]
+    fn create_raw_index_with_settings_applies_all_the_settings() {
+        let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+
+        let settings = Settings::<Unchecked> {
+            searchable_attributes: Setting::Set(vec![S("title")]),
+            displayed_attributes: Setting::Set(vec![S("title")]),
+            stop_words: Setting::Set(std::iter::once(S("the")).collect()),
+            synonyms: Setting::Set(std::iter::once((S("puppy"), vec![S("dog")])).collect()),
+            ranking_rules: Setting::Set(vec![RankingRuleView::Words, RankingRuleView::Typo]),
+            typo_tolerance: Setting::Set(TypoSettings {
+                enabled: Setting::Set(false),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+        .check();
+        let index = index_scheduler
+            .create_raw_index_with_settings("doggos", None, Some("id"), &settings)
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.primary_key(&rtxn).unwrap(), Some("id"));
+        assert_eq!(index.searchable_fields(&rtxn).unwrap(), Some(vec!["title"]));
+        assert_eq!(index.displayed_fields(&rtxn).unwrap(), Some(vec!["title"]));
+        let stop_words = index.stop_words(&rtxn).unwrap().unwrap();
+        assert_eq!(stop_words.len(), 1);
+        assert!(stop_words.contains("the"));
+        let synonyms = index.synonyms(&rtxn).unwrap();
+        assert_eq!(synonyms.get(&vec![S("puppy")]), Some(&vec![vec![S("dog")]]));
+        snapshot!(format!("{:?}", index.criteria(&rtxn).unwrap()), @"[Words, Typo]");
+        assert!(!index.authorize_typos(&rtxn).unwrap());
+        drop(rtxn);
+
+        // The index is registered and can be retrieved by its name.
+        let index = index_scheduler.index("doggos").unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.primary_key(&rtxn).unwrap(), Some("id"));
+    }
+
+    #[test]
+    fn create_raw_index_with_invalid_settings_does_not_create_the_index() {
+        let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+
+        let settings =
+            Settings::<Unchecked> { max_token_length: Setting::Set(0), ..Default::default() }
+                .check();
+        index_scheduler
+            .create_raw_index_with_settings("doggos", None, Some("id"), &settings)
+            .unwrap_err();
+
+        let err = index_scheduler.index("doggos").map(|_| ()).unwrap_err();
+        assert!(matches!(err, Error::IndexNotFound(_)));
+        let rtxn = index_scheduler.env.read_txn().unwrap();
+        assert!(index_scheduler.index_mapper.index_mapping.is_empty(&rtxn).unwrap());
+        drop(rtxn);
+
+        // The name is free again.
+        index_scheduler
+            .create_raw_index_with_settings(
+                "doggos",
+                None,
+                Some("id"),
+                &Settings::<Unchecked>::default().check(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn create_index_beyond_max_indexes() {
+        let (mut index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+        index_scheduler.index_mapper.max_indexes = Some(2);
+
+        index_scheduler.create_raw_index("catto", None).unwrap();
+        index_scheduler.create_raw_index("doggo", None).unwrap();
+        let err = index_scheduler.create_raw_index("girafo", None).map(|_| ()).unwrap_err();
+        assert!(matches!(err, Error::IndexLimitReached { max: 2 }));
+        let err = index_scheduler.index("girafo").map(|_| ()).unwrap_err();
+        assert!(matches!(err, Error::IndexNotFound(_)));
+
+        // The indexes beyond a lowered limit stay usable.
+        index_scheduler.index_mapper.max_indexes = Some(1);
+        index_scheduler.index("catto").unwrap();
+        index_scheduler.create_raw_index("doggo", None).unwrap();
+        let err = index_scheduler.create_raw_index("girafo", None).map(|_| ()).unwrap_err();
+        assert!(matches!(err, Error::IndexLimitReached { max: 1 }));
+    }
 }
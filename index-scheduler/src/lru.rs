@@ -1,12 +1,16 @@
 //! Thread-safe `Vec`-backend LRU cache using [`std::sync::atomic::AtomicU64`] for synchronization.
 
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
 /// Thread-safe `Vec`-backend LRU cache
+///
+/// The generations are shared so that an element can be marked as used through a [`LruHandle`],
+/// without any access to the cache itself.
 #[derive(Debug)]
 pub struct Lru<T> {
-    data: Vec<(AtomicU64, T)>,
-    generation: AtomicU64,
+    data: Vec<(Arc<AtomicU64>, T)>,
+    generation: Arc<AtomicU64>,
     cap: usize,
 }
 
@@ -24,7 +28,7 @@ impl<T> Lru<T> {
         Self {
             // Note: since the element of the vector contains an AtomicU64, it is definitely not zero-sized so cap will never be usize::MAX.
             data: Vec::with_capacity(cap),
-            generation: AtomicU64::new(0),
+            generation: Arc::new(AtomicU64::new(0)),
             cap,
         }
     }
@@ -42,12 +46,6 @@ impl<T> Lru<T> {
         generation + 1
     }
 
-    fn next_generation_mut(&mut self) -> u64 {
-        let generation = self.generation.get_mut();
-        *generation += 1;
-        *generation
-    }
-
     /// Add a value in the cache, evicting an older value if necessary.
     ///
     /// If a value was evicted from the cache, it is returned.
@@ -60,9 +58,9 @@ impl<T> Lru<T> {
         // no need for a memory fence: we assume that whichever mechanism provides us synchronization
         // (very probably, a RwLock) takes care of fencing for us.
 
-        let next_generation = self.next_generation_mut();
+        let next_generation = self.next_generation();
         let evicted = if self.is_full() { self.pop() } else { None };
-        self.data.push((AtomicU64::new(next_generation), value));
+        self.data.push((Arc::new(AtomicU64::new(next_generation)), value));
         evicted
     }
 
@@ -74,12 +72,10 @@ impl<T> Lru<T> {
     ///
     /// - Linear in the capacity of the cache.
     pub fn pop(&mut self) -> Option<T> {
-        // Don't use `Iterator::min_by_key` that provides shared references to its elements,
-        // so that we can get an exclusive one.
-        // This allows to handles the `AtomicU64`s as normal integers without using atomic instructions.
         let mut min_generation_index = None;
-        for (index, (generation, _)) in self.data.iter_mut().enumerate() {
-            let generation = *generation.get_mut();
+        for (index, (generation, _)) in self.data.iter().enumerate() {
+            // Acquire so we see the generations stored by the handles.
+            let generation = generation.load(Ordering::Acquire);
             if let Some((_, min_generation)) = min_generation_index {
                 if min_generation > generation {
                     min_generation_index = Some((index, generation));
@@ -147,16 +143,32 @@ where
     ///
     /// - Linear in the capacity of the cache.
     pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
-        let next_generation = self.0.next_generation_mut();
+        let next_generation = self.0.next_generation();
         for (generation, (candidate, value)) in self.0.data.iter_mut() {
             if key == candidate {
-                *generation.get_mut() = next_generation;
+                generation.store(next_generation, Ordering::Release);
                 return Some(value);
             }
         }
         None
     }
 
+    /// Gets a handle that marks the value of the key as used, without accessing the cache map.
+    ///
+    /// If no value matches, `None` will be returned.
+    ///
+    /// # Complexity
+    ///
+    /// - Linear in the capacity of the cache.
+    pub fn handle(&self, key: &K) -> Option<LruHandle> {
+        self.0.data.iter().find(|(_, (candidate, _))| key == candidate).map(|(generation, _)| {
+            LruHandle {
+                generation: generation.clone(),
+                cache_generation: self.0.generation.clone(),
+            }
+        })
+    }
+
     /// Inserts a value in the cache map by its key, replacing any existing value and returning any evicted value.
     ///
     /// # Complexity
@@ -192,6 +204,24 @@ where
     }
 }
 
+/// Marks an element of a LRU cache as used.
+///
+/// A handle outliving the element doesn't have any effect on the cache.
+#[derive(Debug, Clone)]
+pub struct LruHandle {
+    generation: Arc<AtomicU64>,
+    cache_generation: Arc<AtomicU64>,
+}
+
+impl LruHandle {
+    /// Marks the element as the most recently used one.
+    pub fn touch(&self) {
+        // Acquire so this "happens-before" the store to the data cell (with Release ordering)
+        let generation = self.cache_generation.fetch_add(1, Ordering::Acquire) + 1;
+        self.generation.store(generation, Ordering::Release);
+    }
+}
+
 /// The result of an insertion in a LRU map.
 pub enum InsertionOutcome<K, V> {
     /// The key was not in the cache, the key-value pair has been inserted.
@@ -180,6 +180,7 @@ ApiKeyNotFound                        , InvalidRequest       , NOT_FOUND ;
 BadParameter                          , InvalidRequest       , BAD_REQUEST;
 BadRequest                            , InvalidRequest       , BAD_REQUEST;
 DatabaseSizeLimitReached              , Internal             , INTERNAL_SERVER_ERROR;
+DocumentIdTooLong                     , InvalidRequest       , BAD_REQUEST;
 DocumentNotFound                      , InvalidRequest       , NOT_FOUND;
 DumpAlreadyProcessing                 , InvalidRequest       , CONFLICT;
 DumpNotFound                          , InvalidRequest       , NOT_FOUND;
@@ -197,6 +198,7 @@ ImmutableIndexUid                     , InvalidRequest       , BAD_REQUEST;
 ImmutableIndexUpdatedAt               , InvalidRequest       , BAD_REQUEST;
 IndexAlreadyExists                    , InvalidRequest       , CONFLICT ;
 IndexCreationFailed                   , Internal             , INTERNAL_SERVER_ERROR;
+IndexLimitReached                     , InvalidRequest       , BAD_REQUEST ;
 IndexNotFound                         , InvalidRequest       , NOT_FOUND;
 IndexPrimaryKeyAlreadyExists          , InvalidRequest       , BAD_REQUEST ;
 IndexPrimaryKeyMultipleCandidatesFound, InvalidRequest       , BAD_REQUEST;
@@ -212,6 +214,7 @@ InvalidApiKeyName                     , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyOffset                   , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyUid                      , InvalidRequest       , BAD_REQUEST ;
 InvalidContentType                    , InvalidRequest       , UNSUPPORTED_MEDIA_TYPE ;
+InvalidDocumentBoost                  , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentCsvDelimiter           , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentFields                 , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentGeoField               , InvalidRequest       , BAD_REQUEST ;
@@ -222,6 +225,7 @@ InvalidIndexLimit                     , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexOffset                    , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexPrimaryKey                , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexUid                       , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchAttributeOrder           , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchAttributesToCrop         , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchAttributesToHighlight    , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchAttributesToRetrieve     , InvalidRequest       , BAD_REQUEST ;
@@ -229,20 +233,27 @@ InvalidSearchCropLength               , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchCropMarker               , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchFacets                   , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchFilter                   , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchHighlightMode            , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchHighlightPostTag         , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchHighlightPreTag          , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchHitsPerPage              , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchLimit                    , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchMatchingStrategy         , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchMaxValueLength           , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchNullPolicy               , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchOffset                   , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchPage                     , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchQ                        , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchShowRankingScore         , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchShowMatchedTerms         , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchShowMatchesPosition      , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchSort                     , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsDisplayedAttributes    , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsDistinctAttribute      , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsFaceting               , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsFilterableAttributes   , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsMaxDocumentIdLength    , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsMaxTokenLength         , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsPagination             , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsRankingRules           , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsSearchableAttributes   , InvalidRequest       , BAD_REQUEST ;
@@ -319,6 +330,7 @@ impl ErrorCode for milli::Error {
                     UserError::InvalidDocumentId { .. } | UserError::TooManyDocumentIds { .. } => {
                         Code::InvalidDocumentId
                     }
+                    UserError::DocumentIdTooLong { .. } => Code::DocumentIdTooLong,
                     UserError::NoPrimaryKeyCandidateFound => Code::IndexPrimaryKeyNoCandidateFound,
                     UserError::MultiplePrimaryKeyCandidatesFound { .. } => {
                         Code::IndexPrimaryKeyMultipleCandidatesFound
@@ -329,10 +341,15 @@ impl ErrorCode for milli::Error {
                     UserError::InvalidSortableAttribute { .. } => Code::InvalidSearchSort,
                     UserError::CriterionError(_) => Code::InvalidSettingsRankingRules,
                     UserError::InvalidGeoField { .. } => Code::InvalidDocumentGeoField,
+                    UserError::InvalidDocumentBoost { .. } => Code::InvalidDocumentBoost,
                     UserError::SortError(_) => Code::InvalidSearchSort,
                     UserError::InvalidMinTypoWordLenSetting(_, _) => {
                         Code::InvalidSettingsTypoTolerance
                     }
+                    UserError::InvalidMaxTokenLengthSetting => Code::InvalidSettingsMaxTokenLength,
+                    UserError::InvalidMaxDocumentIdLengthSetting => {
+                        Code::InvalidSettingsMaxDocumentIdLength
+                    }
                 }
             }
         }
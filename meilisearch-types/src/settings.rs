@@ -93,6 +93,9 @@ pub struct TypoSettings {
     #[serde(default, skip_serializing_if = "Setting::is_not_set")]
     #[deserr(default)]
     pub disable_on_attributes: Setting<BTreeSet<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default)]
+    pub disable_on_numbers: Setting<bool>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Deserr)]
@@ -181,6 +184,12 @@ pub struct Settings<T> {
     #[serde(default, skip_serializing_if = "Setting::is_not_set")]
     #[deserr(default, error = DeserrJsonError<InvalidSettingsPagination>)]
     pub pagination: Setting<PaginationSettings>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsMaxTokenLength>)]
+    pub max_token_length: Setting<usize>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsMaxDocumentIdLength>)]
+    pub max_document_id_length: Setting<usize>,
 
     #[serde(skip)]
     #[deserr(skip)]
@@ -201,6 +210,8 @@ impl Settings<Checked> {
             typo_tolerance: Setting::Reset,
             faceting: Setting::Reset,
             pagination: Setting::Reset,
+            max_token_length: Setting::Reset,
+            max_document_id_length: Setting::Reset,
             _kind: PhantomData,
         }
     }
@@ -218,6 +229,8 @@ impl Settings<Checked> {
             typo_tolerance,
             faceting,
             pagination,
+            max_token_length,
+            max_document_id_length,
             ..
         } = self;
 
@@ -233,6 +246,8 @@ impl Settings<Checked> {
             typo_tolerance,
             faceting,
             pagination,
+            max_token_length,
+            max_document_id_length,
             _kind: PhantomData,
         }
     }
@@ -274,6 +289,8 @@ impl Settings<Unchecked> {
             typo_tolerance: self.typo_tolerance,
             faceting: self.faceting,
             pagination: self.pagination,
+            max_token_length: self.max_token_length,
+            max_document_id_length: self.max_document_id_length,
             _kind: PhantomData,
         }
     }
@@ -386,6 +403,12 @@ pub fn apply_settings_to_builder(
                 Setting::Reset => builder.reset_exact_attributes(),
                 Setting::NotSet => (),
             }
+
+            match value.disable_on_numbers {
+                Setting::Set(val) => builder.set_exact_numbers(val),
+                Setting::Reset => builder.reset_exact_numbers(),
+                Setting::NotSet => (),
+            }
         }
         Setting::Reset => {
             // all typo settings need to be reset here.
@@ -394,6 +417,7 @@ pub fn apply_settings_to_builder(
             builder.reset_min_word_len_two_typos();
             builder.reset_exact_words();
             builder.reset_exact_attributes();
+            builder.reset_exact_numbers();
         }
         Setting::NotSet => (),
     }
@@ -417,6 +441,18 @@ pub fn apply_settings_to_builder(
         Setting::Reset => builder.reset_pagination_max_total_hits(),
         Setting::NotSet => (),
     }
+
+    match settings.max_token_length {
+        Setting::Set(max) => builder.set_max_token_length(max),
+        Setting::Reset => builder.reset_max_token_length(),
+        Setting::NotSet => (),
+    }
+
+    match settings.max_document_id_length {
+        Setting::Set(max) => builder.set_max_document_id_length(max),
+        Setting::Reset => builder.reset_max_document_id_length(),
+        Setting::NotSet => (),
+    }
 }
 
 pub fn settings(
@@ -470,6 +506,7 @@ pub fn settings(
         min_word_size_for_typos: Setting::Set(min_typo_word_len),
         disable_on_words: Setting::Set(disabled_words),
         disable_on_attributes: Setting::Set(disabled_attributes),
+        disable_on_numbers: Setting::Set(index.exact_numbers(rtxn)?),
     };
 
     let faceting = FacetingSettings {
@@ -505,6 +542,14 @@ pub fn settings(
         typo_tolerance: Setting::Set(typo_tolerance),
         faceting: Setting::Set(faceting),
         pagination: Setting::Set(pagination),
+        max_token_length: match index.max_token_length(rtxn)? {
+            Some(max) => Setting::Set(max),
+            None => Setting::Reset,
+        },
+        max_document_id_length: match index.max_document_id_length(rtxn)? {
+            Some(max) => Setting::Set(max),
+            None => Setting::Reset,
+        },
         _kind: PhantomData,
     })
 }
@@ -624,6 +669,8 @@ pub(crate) mod test {
             typo_tolerance: Setting::NotSet,
             faceting: Setting::NotSet,
             pagination: Setting::NotSet,
+            max_token_length: Setting::NotSet,
+            max_document_id_length: Setting::NotSet,
             _kind: PhantomData::<Unchecked>,
         };
 
@@ -645,6 +692,8 @@ pub(crate) mod test {
             typo_tolerance: Setting::NotSet,
             faceting: Setting::NotSet,
             pagination: Setting::NotSet,
+            max_token_length: Setting::NotSet,
+            max_document_id_length: Setting::NotSet,
             _kind: PhantomData::<Unchecked>,
         };
 
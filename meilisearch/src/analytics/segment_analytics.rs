@@ -30,8 +30,9 @@ use crate::routes::indexes::documents::UpdateDocumentsQuery;
 use crate::routes::tasks::TasksFilterQuery;
 use crate::routes::{create_all_stats, Stats};
 use crate::search::{
-    SearchQuery, SearchQueryWithIndex, SearchResult, DEFAULT_CROP_LENGTH, DEFAULT_CROP_MARKER,
-    DEFAULT_HIGHLIGHT_POST_TAG, DEFAULT_HIGHLIGHT_PRE_TAG, DEFAULT_SEARCH_LIMIT,
+    HighlightMode, SearchQuery, SearchQueryWithIndex, SearchResult, DEFAULT_CROP_LENGTH,
+    DEFAULT_CROP_MARKER, DEFAULT_HIGHLIGHT_POST_TAG, DEFAULT_HIGHLIGHT_PRE_TAG,
+    DEFAULT_SEARCH_LIMIT,
 };
 use crate::Opt;
 
@@ -237,6 +238,8 @@ struct Infos {
     ignore_snapshot_if_db_exists: bool,
     http_addr: bool,
     http_payload_size_limit: Byte,
+    max_indexes: Option<usize>,
+    repair_indexes: bool,
     log_level: String,
     max_indexing_memory: MaxMemory,
     max_indexing_threads: MaxThreads,
@@ -263,6 +266,8 @@ impl From<Opt> for Infos {
             env,
             max_index_size: _,
             max_task_db_size: _,
+            max_indexes,
+            repair_indexes,
             http_payload_size_limit,
             ssl_cert_path,
             ssl_key_path,
@@ -312,6 +317,8 @@ impl From<Opt> for Infos {
             ignore_snapshot_if_db_exists,
             http_addr: http_addr != default_http_addr(),
             http_payload_size_limit,
+            max_indexes,
+            repair_indexes,
             log_level: log_level.to_string(),
             max_indexing_memory,
             max_indexing_threads,
@@ -518,6 +525,12 @@ pub struct SearchAggregator {
     // every time a search is done, we increment the counter linked to the used settings
     matching_strategy: HashMap<String, usize>,
 
+    // attribute order
+    attribute_order: bool,
+
+    // null policy
+    null_policy: bool,
+
     // pagination
     max_limit: usize,
     max_offset: usize,
@@ -531,7 +544,11 @@ pub struct SearchAggregator {
     max_attributes_to_crop: usize,
     crop_marker: bool,
     show_matches_position: bool,
+    show_matched_terms: bool,
+    show_ranking_score: bool,
     crop_length: bool,
+    max_value_length: bool,
+    highlight_mode: bool,
 
     // facets
     facets_sum_of_terms: usize,
@@ -592,12 +609,18 @@ impl SearchAggregator {
         }
 
         ret.matching_strategy.insert(format!("{:?}", query.matching_strategy), 1);
+        ret.attribute_order = query.attribute_order.is_some();
+        ret.null_policy = query.null_policy.is_some();
 
         ret.highlight_pre_tag = query.highlight_pre_tag != DEFAULT_HIGHLIGHT_PRE_TAG();
         ret.highlight_post_tag = query.highlight_post_tag != DEFAULT_HIGHLIGHT_POST_TAG();
         ret.crop_marker = query.crop_marker != DEFAULT_CROP_MARKER();
         ret.crop_length = query.crop_length != DEFAULT_CROP_LENGTH();
         ret.show_matches_position = query.show_matches_position;
+        ret.show_matched_terms = query.show_matched_terms;
+        ret.show_ranking_score = query.show_ranking_score;
+        ret.max_value_length = query.max_value_length.is_some();
+        ret.highlight_mode = query.highlight_mode != HighlightMode::default();
 
         ret
     }
@@ -660,7 +683,11 @@ impl SearchAggregator {
         self.max_attributes_to_crop = self.max_attributes_to_crop.max(other.max_attributes_to_crop);
         self.crop_marker |= other.crop_marker;
         self.show_matches_position |= other.show_matches_position;
+        self.show_matched_terms |= other.show_matched_terms;
+        self.show_ranking_score |= other.show_ranking_score;
         self.crop_length |= other.crop_length;
+        self.max_value_length |= other.max_value_length;
+        self.highlight_mode |= other.highlight_mode;
 
         // facets
         self.facets_sum_of_terms =
@@ -673,6 +700,12 @@ impl SearchAggregator {
             let matching_strategy = self.matching_strategy.entry(key).or_insert(0);
             *matching_strategy = matching_strategy.saturating_add(value);
         }
+
+        // attribute order
+        self.attribute_order |= other.attribute_order;
+
+        // null policy
+        self.null_policy |= other.null_policy;
     }
 
     pub fn into_event(self, user: &User, event_name: &str) -> Option<Track> {
@@ -720,14 +753,24 @@ impl SearchAggregator {
                     "max_attributes_to_crop": self.max_attributes_to_crop,
                     "crop_marker": self.crop_marker,
                     "show_matches_position": self.show_matches_position,
+                    "show_matched_terms": self.show_matched_terms,
+                    "show_ranking_score": self.show_ranking_score,
                     "crop_length": self.crop_length,
+                    "max_value_length": self.max_value_length,
+                    "highlight_mode": self.highlight_mode,
                 },
                 "facets": {
                     "avg_facets_number": format!("{:.2}", self.facets_sum_of_terms as f64 / self.facets_total_number_of_facets as f64),
                 },
                 "matching_strategy": {
                     "most_used_strategy": self.matching_strategy.iter().max_by_key(|(_, v)| *v).map(|(k, _)| json!(k)).unwrap_or_else(|| json!(null)),
-                }
+                },
+                "attribute_order": {
+                    "used": self.attribute_order,
+                },
+                "null_policy": {
+                    "used": self.null_policy,
+                },
             });
 
             Some(Track {
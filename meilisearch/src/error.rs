@@ -22,6 +22,8 @@ pub enum MeilisearchHttpError {
     DocumentNotFound(String),
     #[error("Invalid syntax for the filter parameter: `expected {}, found: {1}`.", .0.join(", "))]
     InvalidExpression(&'static [&'static str], Value),
+    #[error("The `maxValueLength` parameter must be greater than 0.")]
+    InvalidMaxValueLength,
     #[error("A {0} payload is missing.")]
     MissingPayload(PayloadType),
     #[error("The provided payload reached the size limit.")]
@@ -59,6 +61,7 @@ impl ErrorCode for MeilisearchHttpError {
             MeilisearchHttpError::InvalidContentType(_, _) => Code::InvalidContentType,
             MeilisearchHttpError::DocumentNotFound(_) => Code::DocumentNotFound,
             MeilisearchHttpError::InvalidExpression(_, _) => Code::InvalidSearchFilter,
+            MeilisearchHttpError::InvalidMaxValueLength => Code::InvalidSearchMaxValueLength,
             MeilisearchHttpError::PayloadTooLarge => Code::PayloadTooLarge,
             MeilisearchHttpError::SwapIndexPayloadWrongLength(_) => Code::InvalidSwapIndexes,
             MeilisearchHttpError::IndexUid(e) => e.error_code(),
@@ -32,7 +32,7 @@ use index_scheduler::{IndexScheduler, IndexSchedulerOptions};
 use log::error;
 use meilisearch_auth::AuthController;
 use meilisearch_types::milli::documents::{DocumentsBatchBuilder, DocumentsBatchReader};
-use meilisearch_types::milli::update::{IndexDocumentsConfig, IndexDocumentsMethod};
+use meilisearch_types::milli::update::{IndexDocumentsConfig, IndexDocumentsMethod, Setting};
 use meilisearch_types::settings::apply_settings_to_builder;
 use meilisearch_types::tasks::KindWithContent;
 use meilisearch_types::versioning::{check_version_file, create_version_file};
@@ -234,8 +234,10 @@ fn open_or_create_database_unchecked(
             index_base_map_size: opt.max_index_size.get_bytes() as usize,
             indexer_config: (&opt.indexer_options).try_into()?,
             autobatching_enabled: true,
+            repair_indexes: opt.repair_indexes,
             index_growth_amount: byte_unit::Byte::from_str("10GiB").unwrap().get_bytes() as usize,
             index_count: DEFAULT_INDEX_COUNT,
+            max_indexes: opt.max_indexes,
         })?)
     };
 
@@ -331,6 +333,9 @@ fn import_dump(
         log::info!("Importing the settings.");
         let settings = index_reader.settings()?;
         apply_settings_to_builder(&settings, &mut builder);
+        // The dumped documents were accepted by the instance that dumped them, even those with
+        // an id longer than the current limit, the limit is lifted while they are imported.
+        builder.set_max_document_id_length(usize::MAX);
         builder.execute(|indexing_step| log::debug!("update: {:?}", indexing_step), || false)?;
 
         // 3.3 Import the documents.
@@ -364,6 +369,14 @@ fn import_dump(
         let (builder, user_result) = builder.add_documents(reader)?;
         log::info!("{} documents found.", user_result?);
         builder.execute()?;
+
+        // 3.4 Apply the dumped document id limit to the next documents.
+        let mut builder = milli::update::Settings::new(&mut wtxn, &index, indexer_config);
+        match settings.max_document_id_length {
+            Setting::Set(max) => builder.set_max_document_id_length(max),
+            Setting::Reset | Setting::NotSet => builder.reset_max_document_id_length(),
+        }
+        builder.execute(|indexing_step| log::debug!("update: {:?}", indexing_step), || false)?;
         wtxn.commit()?;
         log::info!("All documents successfully imported.");
     }
@@ -29,6 +29,8 @@ const MEILI_MASTER_KEY: &str = "MEILI_MASTER_KEY";
 const MEILI_ENV: &str = "MEILI_ENV";
 #[cfg(all(not(debug_assertions), feature = "analytics"))]
 const MEILI_NO_ANALYTICS: &str = "MEILI_NO_ANALYTICS";
+const MEILI_MAX_INDEXES: &str = "MEILI_MAX_INDEXES";
+const MEILI_REPAIR_INDEXES: &str = "MEILI_REPAIR_INDEXES";
 const MEILI_HTTP_PAYLOAD_SIZE_LIMIT: &str = "MEILI_HTTP_PAYLOAD_SIZE_LIMIT";
 const MEILI_SSL_CERT_PATH: &str = "MEILI_SSL_CERT_PATH";
 const MEILI_SSL_KEY_PATH: &str = "MEILI_SSL_KEY_PATH";
@@ -172,6 +174,21 @@ pub struct Opt {
     #[serde(skip, default = "default_max_task_db_size")]
     pub max_task_db_size: Byte,
 
+    /// Sets the maximum number of indexes that can be created.
+    ///
+    /// The indexes already created beyond this limit stay usable, only the creation of new ones is refused.
+    #[clap(long, env = MEILI_MAX_INDEXES)]
+    #[serde(default)]
+    pub max_indexes: Option<usize>,
+
+    /// Reconciles the indexes present on disk with the index names when Meilisearch starts.
+    ///
+    /// The names of the indexes whose data is missing are removed, and the index folders
+    /// that no name refers to, left by a crash during an index creation or deletion, are deleted.
+    #[clap(long, env = MEILI_REPAIR_INDEXES)]
+    #[serde(default)]
+    pub repair_indexes: bool,
+
     /// Sets the maximum size of accepted payloads. Value must be given in bytes or explicitly stating a
     /// base unit (for instance: 107374182400, '107.7Gb', or '107374 Mb').
     #[clap(long, env = MEILI_HTTP_PAYLOAD_SIZE_LIMIT, default_value_t = default_http_payload_size_limit())]
@@ -362,6 +379,8 @@ impl Opt {
             env,
             max_index_size: _,
             max_task_db_size: _,
+            max_indexes,
+            repair_indexes,
             http_payload_size_limit,
             ssl_cert_path,
             ssl_key_path,
@@ -396,6 +415,10 @@ impl Opt {
         {
             export_to_env_if_not_present(MEILI_NO_ANALYTICS, no_analytics.to_string());
         }
+        if let Some(max_indexes) = max_indexes {
+            export_to_env_if_not_present(MEILI_MAX_INDEXES, max_indexes.to_string());
+        }
+        export_to_env_if_not_present(MEILI_REPAIR_INDEXES, repair_indexes.to_string());
         export_to_env_if_not_present(
             MEILI_HTTP_PAYLOAD_SIZE_LIMIT,
             http_payload_size_limit.to_string(),
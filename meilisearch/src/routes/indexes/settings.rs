@@ -237,6 +237,9 @@ make_setting_route!(
                     "disable_on_words": setting
                         .as_ref()
                         .and_then(|s| s.disable_on_words.as_ref().set().map(|m| !m.is_empty())),
+                    "disable_on_numbers": setting
+                        .as_ref()
+                        .and_then(|s| s.disable_on_numbers.as_ref().set().copied()),
                     "min_word_size_for_one_typo": setting
                         .as_ref()
                         .and_then(|s| s.min_word_size_for_typos
@@ -439,6 +442,56 @@ make_setting_route!(
     }
 );
 
+make_setting_route!(
+    "/max-token-length",
+    put,
+    usize,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsMaxTokenLength,
+    >,
+    max_token_length,
+    "maxTokenLength",
+    analytics,
+    |max_token_length: &Option<usize>, req: &HttpRequest| {
+        use serde_json::json;
+
+        analytics.publish(
+            "MaxTokenLength Updated".to_string(),
+            json!({
+                "max_token_length": {
+                    "value": max_token_length,
+                },
+            }),
+            Some(req),
+        );
+    }
+);
+
+make_setting_route!(
+    "/max-document-id-length",
+    put,
+    usize,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsMaxDocumentIdLength,
+    >,
+    max_document_id_length,
+    "maxDocumentIdLength",
+    analytics,
+    |max_document_id_length: &Option<usize>, req: &HttpRequest| {
+        use serde_json::json;
+
+        analytics.publish(
+            "MaxDocumentIdLength Updated".to_string(),
+            json!({
+                "max_document_id_length": {
+                    "value": max_document_id_length,
+                },
+            }),
+            Some(req),
+        );
+    }
+);
+
 macro_rules! generate_configure {
     ($($mod:ident),*) => {
         pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -464,7 +517,9 @@ generate_configure!(
     ranking_rules,
     typo_tolerance,
     pagination,
-    faceting
+    faceting,
+    max_token_length,
+    max_document_id_length
 );
 
 pub async fn update_all(
@@ -523,6 +578,11 @@ pub async fn update_all(
                     .as_ref()
                     .set()
                     .and_then(|s| s.disable_on_words.as_ref().set().map(|m| !m.is_empty())),
+                "disable_on_numbers": new_settings.typo_tolerance
+                    .as_ref()
+                    .set()
+                    .and_then(|s| s.disable_on_numbers.as_ref().set())
+                    .copied(),
                 "min_word_size_for_one_typo": new_settings.typo_tolerance
                     .as_ref()
                     .set()
@@ -558,6 +618,12 @@ pub async fn update_all(
             "synonyms": {
                 "total": new_settings.synonyms.as_ref().set().map(|synonyms| synonyms.len()),
             },
+            "max_token_length": {
+                "value": new_settings.max_token_length.as_ref().set(),
+            },
+            "max_document_id_length": {
+                "value": new_settings.max_document_id_length.as_ref().set(),
+            },
         }),
         Some(&req),
     );
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::cmp::min;
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::str::FromStr;
@@ -14,7 +15,7 @@ use meilisearch_types::{milli, Document};
 use milli::tokenizer::TokenizerBuilder;
 use milli::{
     AscDesc, FieldId, FieldsIdsMap, Filter, FormatOptions, Index, MatchBounds, MatcherBuilder,
-    SortError, TermsMatchingStrategy, DEFAULT_VALUES_PER_FACET,
+    SortError, TermsMatchingStrategy, DEFAULT_VALUES_PER_FACET, RESERVED_BOOST_FIELD_NAME,
 };
 use regex::Regex;
 use serde::Serialize;
@@ -54,6 +55,10 @@ pub struct SearchQuery {
     pub attributes_to_highlight: Option<HashSet<String>>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchShowMatchesPosition>, default)]
     pub show_matches_position: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowMatchedTerms>, default)]
+    pub show_matched_terms: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowRankingScore>, default)]
+    pub show_ranking_score: bool,
     #[deserr(default, error = DeserrJsonError<InvalidSearchFilter>)]
     pub filter: Option<Value>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchSort>)]
@@ -68,6 +73,14 @@ pub struct SearchQuery {
     pub crop_marker: String,
     #[deserr(default, error = DeserrJsonError<InvalidSearchMatchingStrategy>, default)]
     pub matching_strategy: MatchingStrategy,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchMaxValueLength>)]
+    pub max_value_length: Option<usize>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchHighlightMode>, default)]
+    pub highlight_mode: HighlightMode,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchAttributeOrder>)]
+    pub attribute_order: Option<Vec<String>>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchNullPolicy>)]
+    pub null_policy: Option<NullPolicy>,
 }
 
 impl SearchQuery {
@@ -105,6 +118,10 @@ pub struct SearchQueryWithIndex {
     pub attributes_to_highlight: Option<HashSet<String>>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchShowMatchesPosition>, default)]
     pub show_matches_position: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowMatchedTerms>, default)]
+    pub show_matched_terms: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchShowRankingScore>, default)]
+    pub show_ranking_score: bool,
     #[deserr(default, error = DeserrJsonError<InvalidSearchFilter>)]
     pub filter: Option<Value>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchSort>)]
@@ -119,6 +136,14 @@ pub struct SearchQueryWithIndex {
     pub crop_marker: String,
     #[deserr(default, error = DeserrJsonError<InvalidSearchMatchingStrategy>, default)]
     pub matching_strategy: MatchingStrategy,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchMaxValueLength>)]
+    pub max_value_length: Option<usize>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchHighlightMode>, default)]
+    pub highlight_mode: HighlightMode,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchAttributeOrder>)]
+    pub attribute_order: Option<Vec<String>>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchNullPolicy>)]
+    pub null_policy: Option<NullPolicy>,
 }
 
 impl SearchQueryWithIndex {
@@ -135,6 +160,8 @@ impl SearchQueryWithIndex {
             crop_length,
             attributes_to_highlight,
             show_matches_position,
+            show_matched_terms,
+            show_ranking_score,
             filter,
             sort,
             facets,
@@ -142,6 +169,10 @@ impl SearchQueryWithIndex {
             highlight_post_tag,
             crop_marker,
             matching_strategy,
+            max_value_length,
+            highlight_mode,
+            attribute_order,
+            null_policy,
         } = self;
         (
             index_uid,
@@ -156,6 +187,8 @@ impl SearchQueryWithIndex {
                 crop_length,
                 attributes_to_highlight,
                 show_matches_position,
+                show_matched_terms,
+                show_ranking_score,
                 filter,
                 sort,
                 facets,
@@ -163,6 +196,10 @@ impl SearchQueryWithIndex {
                 highlight_post_tag,
                 crop_marker,
                 matching_strategy,
+                max_value_length,
+                highlight_mode,
+                attribute_order,
+                null_policy,
                 // do not use ..Default::default() here,
                 // rather add any missing field from `SearchQuery` to `SearchQueryWithIndex`
             },
@@ -194,14 +231,66 @@ impl From<MatchingStrategy> for TermsMatchingStrategy {
     }
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserr)]
+#[deserr(rename_all = camelCase)]
+pub enum HighlightMode {
+    /// Only highlight the part of the words matching the query
+    MatchedSpan,
+    /// Highlight the whole matching words
+    WholeWord,
+}
+
+impl Default for HighlightMode {
+    fn default() -> Self {
+        Self::MatchedSpan
+    }
+}
+
+impl From<HighlightMode> for milli::HighlightMode {
+    fn from(other: HighlightMode) -> Self {
+        match other {
+            HighlightMode::MatchedSpan => Self::MatchedSpan,
+            HighlightMode::WholeWord => Self::WholeWord,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserr)]
+#[deserr(rename_all = camelCase)]
+pub enum NullPolicy {
+    /// The documents without a value are sorted first and match the `<` and `<=` filters
+    NullsFirst,
+    /// The documents without a value are sorted last and match the `>` and `>=` filters
+    NullsLast,
+    /// The documents without a value are neither sorted nor matched by the comparison filters
+    ExcludeNulls,
+}
+
+impl From<NullPolicy> for milli::NullPolicy {
+    fn from(other: NullPolicy) -> Self {
+        match other {
+            NullPolicy::NullsFirst => Self::NullsFirst,
+            NullPolicy::NullsLast => Self::NullsLast,
+            NullPolicy::ExcludeNulls => Self::ExcludeNulls,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct SearchHit {
     #[serde(flatten)]
     pub document: Document,
+    /// The primary key value of the document, returned even if it is not displayed.
+    #[serde(rename = "_id")]
+    pub id: Value,
     #[serde(rename = "_formatted", skip_serializing_if = "Document::is_empty")]
     pub formatted: Document,
     #[serde(rename = "_matchesPosition", skip_serializing_if = "Option::is_none")]
     pub matches_position: Option<MatchesPosition>,
+    #[serde(rename = "_matchedTerms", skip_serializing_if = "Option::is_none")]
+    pub matched_terms: Option<usize>,
+    #[serde(rename = "_rankingScore", skip_serializing_if = "Option::is_none")]
+    pub ranking_score: Option<f64>,
 }
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
@@ -266,6 +355,11 @@ pub fn perform_search(
     query: SearchQuery,
 ) -> Result<SearchResult, MeilisearchHttpError> {
     let before_search = Instant::now();
+
+    if query.max_value_length == Some(0) {
+        return Err(MeilisearchHttpError::InvalidMaxValueLength);
+    }
+
     let rtxn = index.read_txn()?;
 
     let mut search = index.search(&rtxn);
@@ -277,6 +371,14 @@ pub fn perform_search(
     let is_finite_pagination = query.is_finite_pagination();
     search.terms_matching_strategy(query.matching_strategy.into());
 
+    if let Some(ref attribute_order) = query.attribute_order {
+        search.attribute_order(attribute_order.clone());
+    }
+
+    if let Some(null_policy) = query.null_policy {
+        search.null_policy(null_policy.into());
+    }
+
     let max_total_hits = index
         .pagination_max_total_hits(&rtxn)
         .map_err(milli::Error::from)?
@@ -329,6 +431,15 @@ pub fn perform_search(
         .map(|fields| fields.into_iter().collect::<BTreeSet<_>>())
         .unwrap_or_else(|| fields_ids_map.iter().map(|(id, _)| id).collect());
 
+    let searchable_ids = index
+        .searchable_fields_ids(&rtxn)?
+        .map(|fields| fields.into_iter().collect::<BTreeSet<_>>())
+        .unwrap_or_else(|| fields_ids_map.iter().map(|(id, _)| id).collect());
+
+    // The query words can only match the searchable attributes and are only reported in the displayed ones.
+    let matched_terms_ids: BTreeSet<_> =
+        displayed_ids.intersection(&searchable_ids).copied().collect();
+
     let fids = |attrs: &BTreeSet<String>| {
         let mut ids = BTreeSet::new();
         for attr in attrs {
@@ -383,10 +494,16 @@ pub fn perform_search(
         tokenizer_builder.allow_list(&script_lang_map);
     }
 
+    let query_words_count = matching_words.query_words_count();
     let mut formatter_builder = MatcherBuilder::new(matching_words, tokenizer_builder.build());
-    formatter_builder.crop_marker(query.crop_marker);
+    formatter_builder.crop_marker(query.crop_marker.clone());
     formatter_builder.highlight_prefix(query.highlight_pre_tag);
     formatter_builder.highlight_suffix(query.highlight_post_tag);
+    formatter_builder.highlight_mode(query.highlight_mode.into());
+
+    let primary_key = index.primary_key(&rtxn).map_err(milli::Error::from)?;
+    let primary_key_id = primary_key.and_then(|primary_key| fields_ids_map.id(primary_key));
+    let boost_field_id = fields_ids_map.id(RESERVED_BOOST_FIELD_NAME);
 
     let mut documents = Vec::new();
 
@@ -394,7 +511,71 @@ pub fn perform_search(
 
     for (_id, obkv) in documents_iter {
         // First generate a document with all the displayed fields
-        let displayed_document = make_document(&displayed_ids, &fields_ids_map, obkv)?;
+        let mut displayed_document = make_document(&displayed_ids, &fields_ids_map, obkv)?;
+
+        let matched_terms = if query.show_matched_terms {
+            let matched_terms_document = make_document(&matched_terms_ids, &fields_ids_map, obkv)?;
+            Some(count_matched_terms(&matched_terms_document, &formatter_builder))
+        } else {
+            None
+        };
+
+        let ranking_score = if query.show_ranking_score {
+            let searchable_document = make_document(&searchable_ids, &fields_ids_map, obkv)?;
+            let score = ranking_score(&searchable_document, &formatter_builder, query_words_count);
+            let boost = match boost_field_id.and_then(|fid| obkv.get(fid)) {
+                Some(value) => serde_json::from_slice::<Value>(value)?.as_f64().unwrap_or(1.0),
+                None => 1.0,
+            };
+            Some(score * boost)
+        } else {
+            None
+        };
+
+        let (matches_position, formatted) = match query.max_value_length {
+            Some(max_value_length) => {
+                // The matches positions are computed on the original values.
+                let matches_position = if query.show_matches_position {
+                    let (matches_position, _) = format_fields(
+                        &displayed_document,
+                        &fields_ids_map,
+                        &formatter_builder,
+                        &formatted_options,
+                        true,
+                        &displayed_ids,
+                    )?;
+                    matches_position
+                } else {
+                    None
+                };
+
+                // Truncate the long values before formatting them, this way the
+                // `_formatted` values are capped too and the highlight tags are kept intact.
+                truncate_values(
+                    &mut displayed_document,
+                    max_value_length,
+                    &query.crop_marker,
+                    primary_key,
+                );
+                let (_, formatted) = format_fields(
+                    &displayed_document,
+                    &fields_ids_map,
+                    &formatter_builder,
+                    &formatted_options,
+                    false,
+                    &displayed_ids,
+                )?;
+                (matches_position, formatted)
+            }
+            None => format_fields(
+                &displayed_document,
+                &fields_ids_map,
+                &formatter_builder,
+                &formatted_options,
+                query.show_matches_position,
+                &displayed_ids,
+            )?,
+        };
 
         // select the attributes to retrieve
         let attributes_to_retrieve = to_retrieve_ids
@@ -403,20 +584,19 @@ pub fn perform_search(
         let mut document =
             permissive_json_pointer::select_values(&displayed_document, attributes_to_retrieve);
 
-        let (matches_position, formatted) = format_fields(
-            &displayed_document,
-            &fields_ids_map,
-            &formatter_builder,
-            &formatted_options,
-            query.show_matches_position,
-            &displayed_ids,
-        )?;
-
         if let Some(sort) = query.sort.as_ref() {
             insert_geo_distance(sort, &mut document);
         }
 
-        let hit = SearchHit { document, formatted, matches_position };
+        let id = match primary_key_id.and_then(|fid| obkv.get(fid)) {
+            Some(value) => serde_json::from_slice(value)?,
+            None => Value::Null,
+        };
+        // `_id` is reserved, a document field with the same name would be serialized twice.
+        document.remove("_id");
+
+        let hit =
+            SearchHit { document, id, formatted, matches_position, matched_terms, ranking_score };
         documents.push(hit);
     }
 
@@ -489,6 +669,52 @@ fn insert_geo_distance(sorts: &[String], document: &mut Document) {
     }
 }
 
+/// Truncates the strings of the document longer than `max_length` characters
+/// and appends the crop marker to them, the other values are kept as is.
+///
+/// The primary key is never truncated, otherwise the document couldn't be identified anymore.
+fn truncate_values(
+    document: &mut Document,
+    max_length: usize,
+    crop_marker: &str,
+    primary_key: Option<&str>,
+) {
+    fn truncate_value(
+        value: &mut Value,
+        max_length: usize,
+        crop_marker: &str,
+        path: &str,
+        primary_key: Option<&str>,
+    ) {
+        if primary_key == Some(path) {
+            return;
+        }
+
+        match value {
+            Value::String(string) => {
+                if let Some((index, _)) = string.char_indices().nth(max_length) {
+                    string.truncate(index);
+                    string.push_str(crop_marker);
+                }
+            }
+            Value::Array(values) => values
+                .iter_mut()
+                .for_each(|v| truncate_value(v, max_length, crop_marker, path, primary_key)),
+            Value::Object(object) => {
+                for (key, value) in object.iter_mut() {
+                    let path = format!("{path}.{key}");
+                    truncate_value(value, max_length, crop_marker, &path, primary_key);
+                }
+            }
+            Value::Null | Value::Bool(_) | Value::Number(_) => (),
+        }
+    }
+
+    for (key, value) in document.iter_mut() {
+        truncate_value(value, max_length, crop_marker, key, primary_key);
+    }
+}
+
 fn compute_formatted_options(
     attr_to_highlight: &HashSet<String>,
     attr_to_crop: &[String],
@@ -664,6 +890,50 @@ fn format_fields<A: AsRef<[u8]>>(
     Ok((matches_position, document))
 }
 
+/// Returns the relevancy of the document for the query, between 0.0 and 1.0.
+///
+/// The score is the part of the query words found in the searchable attributes of the
+/// document, relative to the theoretical maximum of a document containing all of them.
+/// It only depends on the query and the document, not on the other documents of the index,
+/// and can be compared between the indexes. Every document has a score of 1.0 for an
+/// empty query.
+///
+/// The score returned in the hits is then multiplied by the `_boost` of the document.
+fn ranking_score<A: AsRef<[u8]>>(
+    document: &Document,
+    builder: &MatcherBuilder<'_, A>,
+    query_words_count: usize,
+) -> f64 {
+    if query_words_count == 0 {
+        return 1.0;
+    }
+    let matched = count_matched_terms(document, builder);
+    matched.min(query_words_count) as f64 / query_words_count as f64
+}
+
+/// Returns the number of distinct query words matching any of the values of the document.
+fn count_matched_terms<A: AsRef<[u8]>>(
+    document: &Document,
+    builder: &MatcherBuilder<'_, A>,
+) -> usize {
+    fn leaf_strings<'a>(value: &'a Value, strings: &mut Vec<Cow<'a, str>>) {
+        match value {
+            Value::String(string) => strings.push(Cow::Borrowed(string)),
+            Value::Number(number) => strings.push(Cow::Owned(number.to_string())),
+            Value::Array(values) => values.iter().for_each(|v| leaf_strings(v, strings)),
+            Value::Object(object) => object.values().for_each(|v| leaf_strings(v, strings)),
+            Value::Null | Value::Bool(_) => (),
+        }
+    }
+
+    let mut strings = Vec::new();
+    document.values().for_each(|value| leaf_strings(value, &mut strings));
+
+    let matched: BTreeSet<_> =
+        strings.iter().flat_map(|s| builder.build(s).matched_query_words()).collect();
+    matched.len()
+}
+
 fn format_value<A: AsRef<[u8]>>(
     value: Value,
     builder: &MatcherBuilder<'_, A>,
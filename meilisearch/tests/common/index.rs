@@ -320,6 +320,20 @@ impl Index<'_> {
         self.service.patch_encoded(url, settings, self.encoder).await
     }
 
+    pub async fn update_settings_max_token_length(&self, settings: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/settings/max-token-length", urlencode(self.uid.as_ref()));
+        self.service.put_encoded(url, settings, self.encoder).await
+    }
+
+    pub async fn update_settings_max_document_id_length(
+        &self,
+        settings: Value,
+    ) -> (Value, StatusCode) {
+        let url =
+            format!("/indexes/{}/settings/max-document-id-length", urlencode(self.uid.as_ref()));
+        self.service.put_encoded(url, settings, self.encoder).await
+    }
+
     pub async fn delete_settings(&self) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/settings", urlencode(self.uid.as_ref()));
         self.service.delete(url).await
@@ -1194,6 +1194,28 @@ async fn error_add_documents_bad_document_id() {
     "###);
 }
 
+#[actix_rt::test]
+async fn error_add_documents_too_long_document_id() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    index.create(Some("docid")).await;
+    let documents = json!([
+        {
+            "docid": "a".repeat(600),
+            "content": "foobar"
+        }
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(1).await;
+    let (response, code) = index.get_task(1).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(response["error"]["code"], json!("document_id_too_long"));
+    assert!(response["error"]["message"]
+        .as_str()
+        .unwrap()
+        .ends_with("is too long. A document identifier can not be longer than 512 bytes, this limit can be changed with the `maxDocumentIdLength` setting."));
+}
+
 #[actix_rt::test]
 async fn error_add_documents_missing_document_id() {
     let server = Server::new().await;
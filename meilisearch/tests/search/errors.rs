@@ -303,6 +303,62 @@ async fn search_bad_show_matches_position() {
     "###);
 }
 
+#[actix_rt::test]
+async fn search_bad_show_matched_terms() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.search_post(json!({"showMatchedTerms": "doggo"})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.showMatchedTerms`: expected a boolean, but found a string: `\"doggo\"`",
+      "code": "invalid_search_show_matched_terms",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_show_matched_terms"
+    }
+    "###);
+
+    let (response, code) = index.search_get("showMatchedTerms=doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `showMatchedTerms`: could not parse `doggo` as a boolean, expected either `true` or `false`",
+      "code": "invalid_search_show_matched_terms",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_show_matched_terms"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn search_bad_show_ranking_score() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.search_post(json!({"showRankingScore": "doggo"})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.showRankingScore`: expected a boolean, but found a string: `\"doggo\"`",
+      "code": "invalid_search_show_ranking_score",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_show_ranking_score"
+    }
+    "###);
+
+    let (response, code) = index.search_get("showRankingScore=doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `showRankingScore`: could not parse `doggo` as a boolean, expected either `true` or `false`",
+      "code": "invalid_search_show_ranking_score",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_show_ranking_score"
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn search_bad_facets() {
     let server = Server::new().await;
@@ -321,6 +377,52 @@ async fn search_bad_facets() {
     // Can't make the `attributes_to_highlight` fail with a get search since it'll accept anything as an array of strings.
 }
 
+#[actix_rt::test]
+async fn search_bad_attribute_order() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.search_post(json!({"attributeOrder": "doggo"})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.attributeOrder`: expected an array, but found a string: `\"doggo\"`",
+      "code": "invalid_search_attribute_order",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_attribute_order"
+    }
+    "###);
+    // Can't make the `attribute_order` fail with a get search since it'll accept anything as an array of strings.
+}
+
+#[actix_rt::test]
+async fn search_bad_null_policy() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.search_post(json!({"nullPolicy": "doggo"})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Unknown value `doggo` at `.nullPolicy`: expected one of `nullsFirst`, `nullsLast`, `excludeNulls`",
+      "code": "invalid_search_null_policy",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_null_policy"
+    }
+    "###);
+
+    let (response, code) = index.search_get("nullPolicy=doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Unknown value `doggo` for parameter `nullPolicy`: expected one of `nullsFirst`, `nullsLast`, `excludeNulls`",
+      "code": "invalid_search_null_policy",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_null_policy"
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn search_non_filterable_facets() {
     let server = Server::new().await;
@@ -535,6 +637,45 @@ async fn search_bad_matching_strategy() {
     "###);
 }
 
+#[actix_rt::test]
+async fn search_bad_max_value_length() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.search_post(json!({"maxValueLength": "doggo"})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.maxValueLength`: expected a positive integer, but found a string: `\"doggo\"`",
+      "code": "invalid_search_max_value_length",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_max_value_length"
+    }
+    "###);
+
+    let (response, code) = index.search_get("maxValueLength=doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `maxValueLength`: could not parse `doggo` as a positive integer",
+      "code": "invalid_search_max_value_length",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_max_value_length"
+    }
+    "###);
+
+    let (response, code) = index.search_post(json!({"maxValueLength": 0})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The `maxValueLength` parameter must be greater than 0.",
+      "code": "invalid_search_max_value_length",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_max_value_length"
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn filter_invalid_syntax_object() {
     let server = Server::new().await;
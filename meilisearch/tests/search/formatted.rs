@@ -21,6 +21,7 @@ async fn formatted_contain_wildcard() {
             assert_eq!(
                 response["hits"][0],
                 json!({
+                    "_id": 852,
                     "_formatted": {
                         "id": "852",
                         "cattos": "<em>pésti</em>",
@@ -38,6 +39,7 @@ async fn formatted_contain_wildcard() {
             assert_eq!(
                 response["hits"][0],
                 json!({
+                    "_id": 852,
                     "id": 852,
                     "cattos": "pésti",
                 })
@@ -53,6 +55,7 @@ async fn formatted_contain_wildcard() {
                 assert_eq!(
                     response["hits"][0],
                     json!({
+                        "_id": 852,
                         "id": 852,
                         "cattos": "pésti",
                         "_formatted": {
@@ -74,6 +77,7 @@ async fn formatted_contain_wildcard() {
                 assert_eq!(
                     response["hits"][0],
                     json!({
+                        "_id": 852,
                         "id": 852,
                         "cattos": "pésti",
                         "_formatted": {
@@ -92,6 +96,7 @@ async fn formatted_contain_wildcard() {
             assert_eq!(
                 response["hits"][0],
                 json!({
+                    "_id": 852,
                     "id": 852,
                     "cattos": "pésti",
                     "_formatted": {
@@ -119,6 +124,7 @@ async fn format_nested() {
             assert_eq!(
                 response["hits"][0],
                 json!({
+                    "_id": 852,
                     "doggos": [
                         {
                             "name": "bobby",
@@ -142,6 +148,7 @@ async fn format_nested() {
                 assert_eq!(
                     response["hits"][0],
                     json!({
+                        "_id": 852,
                         "doggos": [
                             {
                                 "name": "bobby",
@@ -164,6 +171,7 @@ async fn format_nested() {
                 assert_eq!(
                     response["hits"][0],
                     json!({
+                        "_id": 852,
                         "doggos": [
                             {
                                 "name": "bobby",
@@ -186,6 +194,7 @@ async fn format_nested() {
             assert_eq!(
                 response["hits"][0],
                 json!({
+                    "_id": 852,
                     "_formatted": {
                         "doggos": [
                             {
@@ -208,6 +217,7 @@ async fn format_nested() {
             assert_eq!(
                 response["hits"][0],
                 json!({
+                    "_id": 852,
                     "_formatted": {
                         "doggos": [
                             {
@@ -230,6 +240,7 @@ async fn format_nested() {
     assert_eq!(
         response["hits"][0],
         json!({
+            "_id": 852,
             "doggos": [
                 {
                     "name": "bobby",
@@ -262,6 +273,7 @@ async fn format_nested() {
     assert_eq!(
         response["hits"][0],
         json!({
+            "_id": 852,
             "_formatted": {
                 "doggos": [
                     {
@@ -300,6 +312,7 @@ async fn displayedattr_2_smol() {
     assert_eq!(
         response["hits"][0],
         json!({
+            "_id": 852,
             "id": 852,
         })
     );
@@ -312,6 +325,7 @@ async fn displayedattr_2_smol() {
             assert_eq!(
                 response["hits"][0],
                 json!({
+                    "_id": 852,
                     "id": 852,
                 })
             );
@@ -324,6 +338,7 @@ async fn displayedattr_2_smol() {
             assert_eq!(
                 response["hits"][0],
                 json!({
+                    "_id": 852,
                     "id": 852,
                     "_formatted": {
                         "id": "852",
@@ -339,6 +354,7 @@ async fn displayedattr_2_smol() {
             assert_eq!(
                 response["hits"][0],
                 json!({
+                    "_id": 852,
                     "id": 852,
                     "_formatted": {
                         "id": "852",
@@ -356,6 +372,7 @@ async fn displayedattr_2_smol() {
                 assert_eq!(
                     response["hits"][0],
                     json!({
+                        "_id": 852,
                         "id": 852,
                         "_formatted": {
                             "id": "852",
@@ -372,6 +389,7 @@ async fn displayedattr_2_smol() {
             assert_eq!(
                 response["hits"][0],
                 json!({
+                    "_id": 852,
                     "id": 852,
                 })
             );
@@ -384,6 +402,7 @@ async fn displayedattr_2_smol() {
             assert_eq!(
                 response["hits"][0],
                 json!({
+                    "_id": 852,
                     "id": 852,
                 })
             );
@@ -393,7 +412,7 @@ async fn displayedattr_2_smol() {
     index
         .search(json!({ "attributesToRetrieve": ["cattos"] }), |response, code| {
             assert_eq!(code, 200, "{}", response);
-            assert_eq!(response["hits"][0], json!({}));
+            assert_eq!(response["hits"][0], json!({ "_id": 852, "_id": 852 }));
         })
         .await;
 
@@ -402,7 +421,7 @@ async fn displayedattr_2_smol() {
             json!({ "attributesToRetrieve": ["cattos"], "attributesToHighlight": ["cattos"], "attributesToCrop": ["cattos"] }),
             |response, code| {
     assert_eq!(code, 200, "{}", response);
-    assert_eq!(response["hits"][0], json!({}));
+    assert_eq!(response["hits"][0], json!({ "_id": 852, "_id": 852 }));
 
             }
         )
@@ -416,6 +435,7 @@ async fn displayedattr_2_smol() {
                 assert_eq!(
                     response["hits"][0],
                     json!({
+                        "_id": 852,
                         "_formatted": {
                             "id": "852",
                         }
@@ -433,6 +453,7 @@ async fn displayedattr_2_smol() {
                 assert_eq!(
                     response["hits"][0],
                     json!({
+                        "_id": 852,
                         "_formatted": {
                             "id": "852",
                         }
@@ -476,3 +497,197 @@ async fn test_cjk_highlight() {
         })
         .await;
 }
+
+#[actix_rt::test]
+async fn max_value_length() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let long_text = "lorem ipsum dolor sit amet ".repeat(10);
+    let documents = json!([
+        { "id": 0, "title": "short", "text": long_text },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    index
+        .search(json!({"q": "lorem", "maxValueLength": 50}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            let mut expected = long_text[..50].to_string();
+            expected.push('…');
+            assert_eq!(
+                response["hits"][0],
+                json!({ "_id": 0, "id": 0, "title": "short", "text": expected })
+            );
+        })
+        .await;
+}
+
+#[actix_rt::test]
+async fn max_value_length_keeps_primary_key_and_caps_formatted() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let id = "3f2b8c1e-6a4d-4f0b-9c7e-2d5a1b8e4f60";
+    let documents = json!([
+        { "id": id, "text": "lorem ipsum dolor sit amet ".repeat(10) },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    index
+        .search(
+            json!({"q": "lorem", "maxValueLength": 20, "attributesToHighlight": ["text"]}),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(
+                    response["hits"][0],
+                    json!({
+                        "_id": id,
+                        "id": id,
+                        "text": "lorem ipsum dolor si…",
+                        "_formatted": {
+                            "id": id,
+                            "text": "<em>lorem</em> ipsum dolor si…",
+                        },
+                    })
+                );
+            },
+        )
+        .await;
+}
+
+#[actix_rt::test]
+async fn max_value_length_uses_crop_marker_and_original_matches_position() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 0, "text": "lorem ipsum dolor sit amet, consectetur adipiscing elit" },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    index
+        .search(
+            json!({
+                "q": "elit",
+                "maxValueLength": 11,
+                "cropMarker": "[…]",
+                "showMatchesPosition": true,
+            }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(
+                    response["hits"][0],
+                    json!({
+                        "_id": 0,
+                        "id": 0,
+                        "text": "lorem ipsum[…]",
+                        "_matchesPosition": {
+                            "text": [{ "start": 51, "length": 4 }],
+                        },
+                    })
+                );
+            },
+        )
+        .await;
+}
+
+#[actix_rt::test]
+async fn highlight_mode() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 0, "title": "Learn programming" },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    index
+        .search(
+            json!({"q": "prog", "attributesToHighlight": ["title"], "showMatchesPosition": true}),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(
+                    response["hits"][0]["_formatted"],
+                    json!({ "id": "0", "title": "Learn <em>prog</em>ramming" })
+                );
+                assert_eq!(
+                    response["hits"][0]["_matchesPosition"],
+                    json!({ "title": [{ "start": 6, "length": 4 }] })
+                );
+            },
+        )
+        .await;
+
+    index
+        .search(
+            json!({
+                "q": "prog",
+                "attributesToHighlight": ["title"],
+                "highlightMode": "wholeWord",
+                "showMatchesPosition": true,
+            }),
+            |response, code| {
+                assert_eq!(code, 200, "{}", response);
+                assert_eq!(
+                    response["hits"][0]["_formatted"],
+                    json!({ "id": "0", "title": "Learn <em>programming</em>" })
+                );
+                assert_eq!(
+                    response["hits"][0]["_matchesPosition"],
+                    json!({ "title": [{ "start": 6, "length": 11 }] })
+                );
+            },
+        )
+        .await;
+}
+
+#[actix_rt::test]
+async fn show_matched_terms() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let documents = json!([
+        { "id": 0, "title": "hello", "text": "beautiful world" },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    index
+        .search(json!({"q": "hello world doggo", "showMatchedTerms": true}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            assert_eq!(response["hits"][0]["_matchedTerms"], json!(2));
+        })
+        .await;
+
+    index
+        .search(json!({"q": "hello world doggo"}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            assert!(response["hits"][0].get("_matchedTerms").is_none());
+        })
+        .await;
+}
+
+#[actix_rt::test]
+async fn show_matched_terms_only_counts_searchable_attributes() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.update_settings(json!({ "searchableAttributes": ["title"] })).await;
+    let documents = json!([
+        { "id": 0, "title": "hello", "text": "hello world" },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(1).await;
+
+    // `world` is in the displayed `text` attribute but it isn't searchable.
+    index
+        .search(json!({"q": "hello world", "showMatchedTerms": true}), |response, code| {
+            assert_eq!(code, 200, "{}", response);
+            assert_eq!(response["hits"][0]["_matchedTerms"], json!(1));
+        })
+        .await;
+}
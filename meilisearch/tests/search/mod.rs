@@ -580,6 +580,27 @@ async fn displayed_attributes() {
     assert!(response["hits"][0].get("title").is_some());
 }
 
+#[actix_rt::test]
+async fn hits_expose_the_primary_key_even_if_not_displayed() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.update_settings(json!({ "displayedAttributes": ["title"] })).await;
+
+    let documents = DOCUMENTS.clone();
+    index.add_documents(documents, None).await;
+    index.wait_task(1).await;
+
+    let (response, code) = index.search_post(json!({})).await;
+    assert_eq!(code, 200, "{}", response);
+    let hits = response["hits"].as_array().unwrap();
+    assert_eq!(hits.len(), 5);
+    for hit in hits {
+        assert!(hit.get("id").is_none(), "{}", hit);
+        assert!(hit["_id"].is_string(), "{}", hit);
+    }
+}
+
 #[actix_rt::test]
 async fn placeholder_search_is_hard_limited() {
     let server = Server::new().await;
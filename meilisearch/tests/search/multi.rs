@@ -72,7 +72,8 @@ async fn simple_search_single_index() {
         "hits": [
           {
             "title": "Gläss",
-            "id": "450465"
+            "id": "450465",
+            "_id": "450465"
           }
         ],
         "query": "glass",
@@ -86,7 +87,8 @@ async fn simple_search_single_index() {
         "hits": [
           {
             "title": "Captain Marvel",
-            "id": "299537"
+            "id": "299537",
+            "_id": "299537"
           }
         ],
         "query": "captain",
@@ -177,7 +179,8 @@ async fn simple_search_two_indexes() {
         "hits": [
           {
             "title": "Gläss",
-            "id": "450465"
+            "id": "450465",
+            "_id": "450465"
           }
         ],
         "query": "glass",
@@ -203,7 +206,8 @@ async fn simple_search_two_indexes() {
                 "age": 4
               }
             ],
-            "cattos": "pésti"
+            "cattos": "pésti",
+            "_id": 852
           },
           {
             "id": 654,
@@ -218,7 +222,8 @@ async fn simple_search_two_indexes() {
             "cattos": [
               "simba",
               "pestiféré"
-            ]
+            ],
+            "_id": 654
           }
         ],
         "query": "pésti",
@@ -231,6 +236,42 @@ async fn simple_search_two_indexes() {
     "###);
 }
 
+#[actix_rt::test]
+async fn ranking_score_two_indexes() {
+    let server = Server::new().await;
+    let index = server.index("small");
+    let documents = json!([
+        { "id": 0, "title": "the dark knight" },
+        { "id": 1, "title": "the dark" },
+    ]);
+    index.add_documents(documents, None).await;
+    index.wait_task(0).await;
+
+    let index = server.index("large");
+    let documents: Vec<_> = (0..50)
+        .map(|id| json!({ "id": id, "title": format!("knight rider {id}") }))
+        .chain(std::iter::once(json!({ "id": 50, "title": "a dark knight" })))
+        .collect();
+    index.add_documents(json!(documents), None).await;
+    index.wait_task(1).await;
+
+    let (response, code) = server
+        .multi_search(json!({"queries": [
+        {"indexUid" : "small", "q": "dark knight", "showRankingScore": true},
+        {"indexUid": "large", "q": "dark knight", "showRankingScore": true},
+        ]}))
+        .await;
+    snapshot!(code, @"200 OK");
+
+    // the top hits of both indexes contain all the query words.
+    for result in response["results"].as_array().unwrap() {
+        let score = result["hits"][0]["_rankingScore"].as_f64().unwrap();
+        assert!((score - 1.0).abs() < f64::EPSILON, "{}", result);
+    }
+    assert_eq!(response["results"][0]["hits"][1]["_rankingScore"], json!(0.5));
+    assert_eq!(response["results"][1]["hits"][1]["_rankingScore"], json!(0.5));
+}
+
 #[actix_rt::test]
 async fn search_one_index_doesnt_exist() {
     let server = Server::new().await;
@@ -274,7 +274,7 @@ async fn settings_bad_typo_tolerance() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "Unknown field `typoTolerance`: expected one of `enabled`, `minWordSizeForTypos`, `disableOnWords`, `disableOnAttributes`",
+      "message": "Unknown field `typoTolerance`: expected one of `enabled`, `minWordSizeForTypos`, `disableOnWords`, `disableOnAttributes`, `disableOnNumbers`",
       "code": "invalid_settings_typo_tolerance",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid_settings_typo_tolerance"
@@ -337,3 +337,59 @@ async fn settings_bad_pagination() {
     }
     "###);
 }
+
+#[actix_rt::test]
+async fn settings_bad_max_token_length() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.update_settings(json!({ "maxTokenLength": "doggo" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.maxTokenLength`: expected a positive integer, but found a string: `\"doggo\"`",
+      "code": "invalid_settings_max_token_length",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_max_token_length"
+    }
+    "###);
+
+    let (response, code) = index.update_settings_max_token_length(json!("doggo")).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type: expected a positive integer, but found a string: `\"doggo\"`",
+      "code": "invalid_settings_max_token_length",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_max_token_length"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn settings_bad_max_document_id_length() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.update_settings(json!({ "maxDocumentIdLength": "doggo" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.maxDocumentIdLength`: expected a positive integer, but found a string: `\"doggo\"`",
+      "code": "invalid_settings_max_document_id_length",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_max_document_id_length"
+    }
+    "###);
+
+    let (response, code) = index.update_settings_max_document_id_length(json!("doggo")).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type: expected a positive integer, but found a string: `\"doggo\"`",
+      "code": "invalid_settings_max_document_id_length",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_max_document_id_length"
+    }
+    "###);
+}
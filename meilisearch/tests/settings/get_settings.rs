@@ -29,6 +29,8 @@ static DEFAULT_SETTINGS_VALUES: Lazy<HashMap<&'static str, Value>> = Lazy::new(|
             "maxTotalHits": json!(1000),
         }),
     );
+    map.insert("max_token_length", json!(Value::Null));
+    map.insert("max_document_id_length", json!(Value::Null));
     map
 });
 
@@ -48,7 +50,7 @@ async fn get_settings() {
     let (response, code) = index.settings().await;
     assert_eq!(code, 200);
     let settings = response.as_object().unwrap();
-    assert_eq!(settings.keys().len(), 11);
+    assert_eq!(settings.keys().len(), 13);
     assert_eq!(settings["displayedAttributes"], json!(["*"]));
     assert_eq!(settings["searchableAttributes"], json!(["*"]));
     assert_eq!(settings["filterableAttributes"], json!([]));
@@ -71,6 +73,18 @@ async fn get_settings() {
             "maxTotalHits": 1000,
         })
     );
+    assert_eq!(
+        settings["typoTolerance"],
+        json!({
+            "enabled": true,
+            "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 },
+            "disableOnWords": [],
+            "disableOnAttributes": [],
+            "disableOnNumbers": true,
+        })
+    );
+    assert_eq!(settings["maxTokenLength"], json!(null));
+    assert_eq!(settings["maxDocumentIdLength"], json!(null));
 }
 
 #[actix_rt::test]
@@ -101,6 +115,63 @@ async fn test_partial_update() {
     assert_eq!(response["searchableAttributes"], json!(["bar"]));
 }
 
+#[actix_rt::test]
+async fn set_and_reset_disable_on_numbers() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.add_documents(json!([{ "id": 0, "code": "123456" }]), None).await;
+    index.wait_task(0).await;
+
+    let (response, code) = index.search_post(json!({ "q": "123457" })).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response["hits"], json!([]));
+
+    index.update_settings(json!({ "typoTolerance": { "disableOnNumbers": false } })).await;
+    index.wait_task(1).await;
+
+    let (response, _) = index.settings().await;
+    assert_eq!(response["typoTolerance"]["disableOnNumbers"], json!(false));
+    let (response, code) = index.search_post(json!({ "q": "123457" })).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response["hits"], json!([{ "id": 0, "code": "123456", "_id": 0 }]));
+
+    index.update_settings(json!({ "typoTolerance": { "disableOnNumbers": null } })).await;
+    index.wait_task(2).await;
+
+    let (response, _) = index.settings().await;
+    assert_eq!(response["typoTolerance"]["disableOnNumbers"], json!(true));
+}
+
+#[actix_rt::test]
+async fn set_and_reset_max_token_length() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    index.add_documents(json!([{ "id": 0, "text": "supercalifragilistic" }]), None).await;
+    index.wait_task(0).await;
+
+    index.update_settings(json!({ "maxTokenLength": 5 })).await;
+    index.wait_task(1).await;
+
+    let (response, _) = index.settings().await;
+    assert_eq!(response["maxTokenLength"], json!(5));
+    let (response, code) = index.search_post(json!({ "q": "supermarket" })).await;
+    assert_eq!(code, 200, "{}", response);
+    assert_eq!(response["hits"], json!([{ "id": 0, "text": "supercalifragilistic", "_id": 0 }]));
+
+    index.update_settings(json!({ "maxTokenLength": 0 })).await;
+    let response = index.wait_task(2).await;
+    assert_eq!(response["status"], "failed");
+    assert_eq!(response["error"]["code"], "invalid_settings_max_token_length");
+
+    index.update_settings(json!({ "maxTokenLength": null })).await;
+    index.wait_task(3).await;
+
+    let (response, _) = index.settings().await;
+    assert_eq!(response["maxTokenLength"], json!(null));
+}
+
 #[actix_rt::test]
 async fn error_delete_settings_unexisting_index() {
     let server = Server::new().await;
@@ -269,7 +340,9 @@ test_setting_routes!(
     ranking_rules put,
     synonyms put,
     pagination patch,
-    faceting patch
+    faceting patch,
+    max_token_length put,
+    max_document_id_length put
 );
 
 #[actix_rt::test]
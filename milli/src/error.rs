@@ -103,11 +103,19 @@ A document identifier can be of type integer or string, \
 only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and underscores (_).", .document_id.to_string()
     )]
     InvalidDocumentId { document_id: Value },
+    #[error(
+        "Document identifier `{}` is too long. \
+A document identifier can not be longer than {max_length} bytes, \
+this limit can be changed with the `maxDocumentIdLength` setting.", .document_id.to_string()
+    )]
+    DocumentIdTooLong { document_id: Value, max_length: usize },
     #[error("Invalid facet distribution, {}", format_invalid_filter_distribution(.invalid_facets_name, .valid_facets_name))]
     InvalidFacetsDistribution {
         invalid_facets_name: BTreeSet<String>,
         valid_facets_name: BTreeSet<String>,
     },
+    #[error("The `_boost` field in the document with the id: `{document_id}` is invalid. Was expecting a positive finite number but instead got `{value}`.")]
+    InvalidDocumentBoost { document_id: Value, value: Value },
     #[error(transparent)]
     InvalidGeoField(#[from] GeoError),
     #[error("{0}")]
@@ -150,6 +158,10 @@ only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and undersco
     UnknownInternalDocumentId { document_id: DocumentId },
     #[error("`minWordSizeForTypos` setting is invalid. `oneTypo` and `twoTypos` fields should be between `0` and `255`, and `twoTypos` should be greater or equals to `oneTypo` but found `oneTypo: {0}` and twoTypos: {1}`.")]
     InvalidMinTypoWordLenSetting(u8, u8),
+    #[error("`maxTokenLength` setting is invalid. It should be greater than `0`.")]
+    InvalidMaxTokenLengthSetting,
+    #[error("`maxDocumentIdLength` setting is invalid. It should be greater than `0`.")]
+    InvalidMaxDocumentIdLengthSetting,
 }
 
 #[derive(Error, Debug)]
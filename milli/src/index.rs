@@ -1,8 +1,9 @@
 use std::borrow::Cow;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::File;
 use std::mem::size_of;
 use std::path::Path;
+use std::time::Duration;
 
 use charabia::{Language, Script};
 use heed::flags::Flags;
@@ -10,9 +11,10 @@ use heed::types::*;
 use heed::{CompactionOption, Database, PolyDatabase, RoTxn, RwTxn};
 use roaring::RoaringBitmap;
 use rstar::RTree;
+use serde::de::DeserializeOwned;
 use time::OffsetDateTime;
 
-use crate::error::{InternalError, UserError};
+use crate::error::{FieldIdMapMissingEntry, InternalError, UserError};
 use crate::facet::FacetType;
 use crate::fields_ids_map::FieldsIdsMap;
 use crate::heed_codec::facet::{
@@ -21,10 +23,11 @@ use crate::heed_codec::facet::{
 };
 use crate::heed_codec::{ScriptLanguageCodec, StrRefCodec};
 use crate::{
-    default_criteria, BEU32StrCodec, BoRoaringBitmapCodec, CboRoaringBitmapCodec, Criterion,
-    DocumentId, ExternalDocumentsIds, FacetDistribution, FieldDistribution, FieldId,
-    FieldIdWordCountCodec, GeoPoint, ObkvCodec, Result, RoaringBitmapCodec, RoaringBitmapLenCodec,
-    Search, StrBEU32Codec, U8StrStrCodec, BEU16, BEU32,
+    default_criteria, obkv_to_json, BEU32StrCodec, BoRoaringBitmapCodec, CboRoaringBitmapCodec,
+    Criterion, DocumentId, ExternalDocumentsIds, FacetDistribution, FieldDistribution, FieldId,
+    FieldIdWordCountCodec, Filter, GeoPoint, ObkvCodec, Position, QueryAst, QueryExplanation,
+    Result, RoaringBitmapCodec, RoaringBitmapLenCodec, Search, SearchOptions, SearchResult,
+    StrBEU32Codec, U8StrStrCodec, BEU16, BEU32,
 };
 
 pub const DEFAULT_MIN_WORD_LEN_ONE_TYPO: u8 = 5;
@@ -36,6 +39,8 @@ pub mod main_key {
     pub const DISTINCT_FIELD_KEY: &str = "distinct-field-key";
     pub const DOCUMENTS_IDS_KEY: &str = "documents-ids";
     pub const SOFT_DELETED_DOCUMENTS_IDS_KEY: &str = "soft-deleted-documents-ids";
+    pub const SOFT_DELETION_DATES_KEY: &str = "soft-deletion-dates";
+    pub const DELETION_GRACE_KEY: &str = "deletion-grace";
     pub const HIDDEN_FACETED_FIELDS_KEY: &str = "hidden-faceted-fields";
     pub const FILTERABLE_FIELDS_KEY: &str = "filterable-fields";
     pub const SORTABLE_FIELDS_KEY: &str = "sortable-fields";
@@ -52,17 +57,24 @@ pub mod main_key {
     pub const STOP_WORDS_KEY: &str = "stop-words";
     pub const STRING_FACETED_DOCUMENTS_IDS_PREFIX: &str = "string-faceted-documents-ids";
     pub const SYNONYMS_KEY: &str = "synonyms";
+    pub const USER_DEFINED_SYNONYMS_KEY: &str = "user-defined-synonyms";
     pub const WORDS_FST_KEY: &str = "words-fst";
     pub const WORDS_PREFIXES_FST_KEY: &str = "words-prefixes-fst";
     pub const CREATED_AT_KEY: &str = "created-at";
     pub const UPDATED_AT_KEY: &str = "updated-at";
     pub const AUTHORIZE_TYPOS: &str = "authorize-typos";
+    pub const EXACT_NUMBERS: &str = "exact-numbers";
     pub const ONE_TYPO_WORD_LEN: &str = "one-typo-word-len";
     pub const TWO_TYPOS_WORD_LEN: &str = "two-typos-word-len";
     pub const EXACT_WORDS: &str = "exact-words";
     pub const EXACT_ATTRIBUTES: &str = "exact-attributes";
+    pub const NON_PREFIX_ATTRIBUTES: &str = "non-prefix-attributes";
+    pub const ATTRIBUTES_COVERAGE: &str = "attributes-coverage";
     pub const MAX_VALUES_PER_FACET: &str = "max-values-per-facet";
     pub const PAGINATION_MAX_TOTAL_HITS: &str = "pagination-max-total-hits";
+    pub const MAX_TOKEN_LENGTH: &str = "max-token-length";
+    pub const MAX_DOCUMENT_ID_LENGTH: &str = "max-document-id-length";
+    pub const MIN_EXACT_RESULTS: &str = "min-exact-results";
 }
 
 pub mod db_name {
@@ -232,6 +244,9 @@ impl Index {
                 main_key::CREATED_AT_KEY,
                 &created_at,
             )?;
+            // The numbers are matched exactly in the new indexes only, the indexes
+            // created before this setting existed keep matching them with typos.
+            main.put::<_, Str, OwnedType<u8>>(&mut txn, main_key::EXACT_NUMBERS, &1)?;
             txn.commit()?;
         }
         Ok(())
@@ -334,6 +349,55 @@ impl Index {
             .unwrap_or_default())
     }
 
+    /// Writes the unix timestamps at which the soft deleted documents were deleted.
+    pub(crate) fn put_soft_deletion_dates(
+        &self,
+        wtxn: &mut RwTxn,
+        dates: &BTreeMap<DocumentId, i64>,
+    ) -> heed::Result<()> {
+        self.main.put::<_, Str, SerdeBincode<BTreeMap<DocumentId, i64>>>(
+            wtxn,
+            main_key::SOFT_DELETION_DATES_KEY,
+            dates,
+        )
+    }
+
+    /// Returns the unix timestamps at which the soft deleted documents were deleted,
+    /// the documents deleted without a deletion grace are not listed.
+    pub(crate) fn soft_deletion_dates(
+        &self,
+        rtxn: &RoTxn,
+    ) -> heed::Result<BTreeMap<DocumentId, i64>> {
+        Ok(self
+            .main
+            .get::<_, Str, SerdeBincode<BTreeMap<DocumentId, i64>>>(
+                rtxn,
+                main_key::SOFT_DELETION_DATES_KEY,
+            )?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn delete_soft_deletion_dates(&self, wtxn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(wtxn, main_key::SOFT_DELETION_DATES_KEY)
+    }
+
+    /* deletion grace */
+
+    /// Writes the duration during which the deleted documents are kept in the database.
+    pub(crate) fn put_deletion_grace(&self, wtxn: &mut RwTxn, grace: Duration) -> heed::Result<()> {
+        self.main.put::<_, Str, SerdeJson<Duration>>(wtxn, main_key::DELETION_GRACE_KEY, &grace)
+    }
+
+    /// Returns the duration during which the deleted documents are kept in the database
+    /// before being definitely removed, they are excluded from the results meanwhile.
+    pub fn deletion_grace(&self, rtxn: &RoTxn) -> heed::Result<Option<Duration>> {
+        self.main.get::<_, Str, SerdeJson<Duration>>(rtxn, main_key::DELETION_GRACE_KEY)
+    }
+
+    pub(crate) fn delete_deletion_grace(&self, wtxn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(wtxn, main_key::DELETION_GRACE_KEY)
+    }
+
     /* primary key */
 
     /// Writes the documents primary key, this is the field name that is used to store the id.
@@ -938,6 +1002,31 @@ impl Index {
         self.main.delete::<_, Str>(wtxn, main_key::SYNONYMS_KEY)
     }
 
+    pub(crate) fn put_user_defined_synonyms(
+        &self,
+        wtxn: &mut RwTxn,
+        synonyms: &HashMap<String, Vec<String>>,
+    ) -> heed::Result<()> {
+        self.main.put::<_, Str, SerdeBincode<_>>(
+            wtxn,
+            main_key::USER_DEFINED_SYNONYMS_KEY,
+            synonyms,
+        )
+    }
+
+    pub(crate) fn delete_user_defined_synonyms(&self, wtxn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(wtxn, main_key::USER_DEFINED_SYNONYMS_KEY)
+    }
+
+    /// The synonyms as they were sent by the user, before being normalized.
+    /// `None` if they were set before being kept as is.
+    pub(crate) fn user_defined_synonyms(
+        &self,
+        rtxn: &RoTxn,
+    ) -> heed::Result<Option<HashMap<String, Vec<String>>>> {
+        self.main.get::<_, Str, SerdeBincode<_>>(rtxn, main_key::USER_DEFINED_SYNONYMS_KEY)
+    }
+
     pub fn synonyms(&self, rtxn: &RoTxn) -> heed::Result<HashMap<Vec<String>, Vec<Vec<String>>>> {
         Ok(self
             .main
@@ -985,6 +1074,22 @@ impl Index {
         self.word_docids.remap_data_type::<RoaringBitmapLenCodec>().get(rtxn, word)
     }
 
+    /// Returns the documents containing the given normalized word, with the absolute positions of
+    /// the word in each of them, see [`crate::relative_from_absolute_position`].
+    pub fn postings(&self, rtxn: &RoTxn, word: &str) -> Result<Vec<(DocumentId, Vec<Position>)>> {
+        let mut docids = self.word_docids.get(rtxn, word)?.unwrap_or_default();
+        docids |= self.exact_word_docids.get(rtxn, word)?.unwrap_or_default();
+        docids -= self.soft_deleted_documents_ids(rtxn)?;
+
+        let mut postings = Vec::with_capacity(docids.len() as usize);
+        for docid in docids {
+            let positions = self.docid_word_positions.get(rtxn, &(docid, word))?;
+            postings.push((docid, positions.map_or_else(Vec::new, |p| p.into_iter().collect())));
+        }
+
+        Ok(postings)
+    }
+
     /* documents */
 
     /// Returns a [`Vec`] of the requested documents. Returns an error if a document is missing.
@@ -1027,6 +1132,106 @@ impl Index {
             }))
     }
 
+    /// Returns the requested documents serialized as a single JSON array.
+    ///
+    /// The stored field values are already JSON encoded, they are copied as-is into the
+    /// array without being deserialized. Unknown and soft deleted ids are skipped.
+    pub fn documents_raw_array(
+        &self,
+        rtxn: &RoTxn,
+        ids: impl IntoIterator<Item = DocumentId>,
+    ) -> Result<Vec<u8>> {
+        let soft_deleted_documents = self.soft_deleted_documents_ids(rtxn)?;
+        let fields_ids_map = self.fields_ids_map(rtxn)?;
+        let mut buffer = vec![b'['];
+        let mut first_document = true;
+
+        for id in ids {
+            if soft_deleted_documents.contains(id) {
+                continue;
+            }
+            let obkv = match self.documents.get(rtxn, &BEU32::new(id))? {
+                Some(obkv) => obkv,
+                None => continue,
+            };
+
+            if !first_document {
+                buffer.push(b',');
+            }
+            first_document = false;
+
+            buffer.push(b'{');
+            for (i, (field_id, value)) in obkv.iter().enumerate() {
+                let name =
+                    fields_ids_map.name(field_id).ok_or(FieldIdMapMissingEntry::FieldId {
+                        field_id,
+                        process: "documents_raw_array",
+                    })?;
+                if i != 0 {
+                    buffer.push(b',');
+                }
+                serde_json::to_writer(&mut buffer, name).map_err(InternalError::SerdeJson)?;
+                buffer.push(b':');
+                buffer.extend_from_slice(value);
+            }
+            buffer.push(b'}');
+        }
+
+        buffer.push(b']');
+        Ok(buffer)
+    }
+
+    /// Returns the document with the given external id deserialized from its displayed fields,
+    /// or `None` if there is no such document.
+    pub fn document<T: DeserializeOwned>(
+        &self,
+        rtxn: &RoTxn,
+        external_id: &str,
+    ) -> Result<Option<T>> {
+        match self.external_documents_ids(rtxn)?.get(external_id) {
+            Some(docid) => Ok(self.deserialize_documents(rtxn, Some(docid))?.pop()),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns a page of the documents matching the filter, ordered by internal id,
+    /// and the total number of documents matching the filter.
+    pub fn documents_where<T: DeserializeOwned>(
+        &self,
+        rtxn: &RoTxn,
+        filter: &Filter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<T>, u64)> {
+        let candidates = filter.evaluate(rtxn, self)? - self.soft_deleted_documents_ids(rtxn)?;
+        let documents =
+            self.deserialize_documents(rtxn, candidates.iter().skip(offset).take(limit))?;
+        Ok((documents, candidates.len()))
+    }
+
+    /// Deserializes the displayed fields of the requested documents.
+    fn deserialize_documents<T: DeserializeOwned>(
+        &self,
+        rtxn: &RoTxn,
+        ids: impl IntoIterator<Item = DocumentId>,
+    ) -> Result<Vec<T>> {
+        let fields_ids_map = self.fields_ids_map(rtxn)?;
+        let displayed_fields = match self.displayed_fields_ids(rtxn)? {
+            Some(fields) => fields,
+            None => fields_ids_map.ids().collect(),
+        };
+
+        let mut documents = Vec::new();
+        for (_id, obkv) in self.documents(rtxn, ids)? {
+            let object = obkv_to_json(&displayed_fields, &fields_ids_map, obkv)?;
+            let document = serde_json::from_value(serde_json::Value::Object(object))
+                .map_err(InternalError::SerdeJson)?;
+            documents.push(document);
+        }
+
+        Ok(documents)
+    }
+
     pub fn facets_distribution<'a>(&'a self, rtxn: &'a RoTxn) -> FacetDistribution<'a> {
         FacetDistribution::new(rtxn, self)
     }
@@ -1035,6 +1240,83 @@ impl Index {
         Search::new(rtxn, self)
     }
 
+    /// Searches the words, phrases and filter of an already built query,
+    /// a [`Search`] with a query string is parsed into the same terms.
+    pub fn search_ast(
+        &self,
+        rtxn: &RoTxn,
+        query: &QueryAst,
+        options: &SearchOptions,
+    ) -> Result<SearchResult> {
+        crate::search::search_ast(self, rtxn, query, options)
+    }
+
+    /// Returns how the given query is interpreted by the engine: its normalized words and,
+    /// for each of them, the synonyms, typo variants, prefix expansions and split words that
+    /// are searched, along with the concatenations of consecutive words.
+    ///
+    /// The query is understood like a default [`Search`]: the typo tolerance of the index
+    /// applies and the words beyond the default words limit are ignored.
+    /// No document is scored, only the words dictionary of the index is read, and the
+    /// documents of the exact query words when a minimum of exact results is set.
+    pub fn explain_query(&self, rtxn: &RoTxn, query: &str) -> Result<QueryExplanation> {
+        crate::search::explain_query(self, rtxn, query)
+    }
+
+    /// Returns the query with its unknown words replaced by the most frequent words of the
+    /// index that are close enough, or `None` if there is nothing to correct.
+    pub fn suggest_correction(&self, rtxn: &RoTxn, query: &str) -> Result<Option<String>> {
+        crate::search::suggest_correction(self, rtxn, query)
+    }
+
+    /// Returns the documents that look the most like the given document, the most similar first.
+    ///
+    /// The words of the document are weighted by tf-idf and the most significant ones are used
+    /// as the query of a search from which the document itself is removed.
+    pub fn more_like_this(
+        &self,
+        rtxn: &RoTxn,
+        docid: DocumentId,
+        limit: usize,
+    ) -> Result<Vec<DocumentId>> {
+        // The number of words of the document that are used to build the query.
+        const SIGNIFICANT_WORDS: usize = 10;
+
+        // The document must exist and not be soft deleted, like for the `documents` method.
+        self.documents(rtxn, Some(docid))?;
+
+        let number_of_documents = self.number_of_documents(rtxn)? as f64;
+        let mut words = Vec::new();
+        for result in self.docid_word_positions.prefix_iter(rtxn, &(docid, ""))? {
+            let ((_, word), positions) = result?;
+            let documents_count = self.word_documents_count(rtxn, word)?.unwrap_or(1) as f64;
+            let idf = (1.0 + number_of_documents / documents_count).ln();
+            words.push((positions.len() as f64 * idf, word));
+        }
+
+        if words.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // The most significant words first, the query terms matching strategy
+        // removes the last words first when there is not enough results.
+        words.sort_unstable_by(|(la, wa), (lb, wb)| lb.total_cmp(la).then_with(|| wa.cmp(wb)));
+        let query: Vec<_> = words.into_iter().take(SIGNIFICANT_WORDS).map(|(_, w)| w).collect();
+
+        let mut search = self.search(rtxn);
+        search
+            .query(query.join(" "))
+            .words_limit(SIGNIFICANT_WORDS)
+            .authorize_typos(false)
+            // the document itself will be part of the results
+            .limit(limit.saturating_add(1));
+        let mut documents_ids = search.execute()?.documents_ids;
+
+        documents_ids.retain(|id| *id != docid);
+        documents_ids.truncate(limit);
+        Ok(documents_ids)
+    }
+
     /// Returns the index creation time.
     pub fn created_at(&self, rtxn: &RoTxn) -> Result<OffsetDateTime> {
         Ok(self
@@ -1084,6 +1366,22 @@ impl Index {
         Ok(())
     }
 
+    /// Returns whether the query words only made of digits are matched without typos.
+    pub fn exact_numbers(&self, txn: &RoTxn) -> heed::Result<bool> {
+        // The absence of a value is false, the setting is only set on the new indexes
+        // to not change the results of the indexes created before it existed.
+        match self.main.get::<_, Str, OwnedType<u8>>(txn, main_key::EXACT_NUMBERS)? {
+            None | Some(0) => Ok(false),
+            Some(_) => Ok(true),
+        }
+    }
+
+    pub(crate) fn put_exact_numbers(&self, txn: &mut RwTxn, flag: bool) -> heed::Result<()> {
+        self.main.put::<_, Str, OwnedType<u8>>(txn, main_key::EXACT_NUMBERS, &(flag as u8))?;
+
+        Ok(())
+    }
+
     pub fn min_word_len_one_typo(&self, txn: &RoTxn) -> heed::Result<u8> {
         // It is not possible to put a bool in heed with OwnedType, so we put a u8 instead. We
         // identify 0 as being false, and anything else as true. The absence of a value is true,
@@ -1167,6 +1465,74 @@ impl Index {
         self.main.delete::<_, Str>(txn, main_key::EXACT_ATTRIBUTES)
     }
 
+    /// Returns the attributes in which the last word of the queries is not matched as a prefix.
+    pub fn non_prefix_attributes<'t>(&self, txn: &'t RoTxn) -> Result<Vec<&'t str>> {
+        Ok(self
+            .main
+            .get::<_, Str, SerdeBincode<Vec<&str>>>(txn, main_key::NON_PREFIX_ATTRIBUTES)?
+            .unwrap_or_default())
+    }
+
+    /// Returns the list of non prefix attributes field ids.
+    pub fn non_prefix_attributes_ids(&self, txn: &RoTxn) -> Result<HashSet<FieldId>> {
+        let attrs = self.non_prefix_attributes(txn)?;
+        let fid_map = self.fields_ids_map(txn)?;
+        Ok(attrs.iter().filter_map(|attr| fid_map.id(attr)).collect())
+    }
+
+    /// Writes the non prefix attributes to the database.
+    pub(crate) fn put_non_prefix_attributes(&self, txn: &mut RwTxn, attrs: &[&str]) -> Result<()> {
+        self.main.put::<_, Str, SerdeBincode<&[&str]>>(
+            txn,
+            main_key::NON_PREFIX_ATTRIBUTES,
+            &attrs,
+        )?;
+        Ok(())
+    }
+
+    /// Clears the non prefix attributes from the store.
+    pub(crate) fn delete_non_prefix_attributes(&self, txn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(txn, main_key::NON_PREFIX_ATTRIBUTES)
+    }
+
+    /// Returns the minimum number of query words that must match in an attribute
+    /// for its position to be taken into account by the attribute ranking rule.
+    pub fn attributes_coverage(&self, txn: &RoTxn) -> heed::Result<BTreeMap<String, usize>> {
+        Ok(self
+            .main
+            .get::<_, Str, SerdeBincode<BTreeMap<String, usize>>>(
+                txn,
+                main_key::ATTRIBUTES_COVERAGE,
+            )?
+            .unwrap_or_default())
+    }
+
+    /// Returns the attributes coverage by field id.
+    pub fn attributes_coverage_ids(&self, txn: &RoTxn) -> Result<HashMap<FieldId, usize>> {
+        let coverage = self.attributes_coverage(txn)?;
+        let fid_map = self.fields_ids_map(txn)?;
+        Ok(coverage
+            .iter()
+            .filter_map(|(attr, min)| fid_map.id(attr).map(|fid| (fid, *min)))
+            .collect())
+    }
+
+    pub(crate) fn put_attributes_coverage(
+        &self,
+        txn: &mut RwTxn,
+        coverage: &BTreeMap<String, usize>,
+    ) -> heed::Result<()> {
+        self.main.put::<_, Str, SerdeBincode<BTreeMap<String, usize>>>(
+            txn,
+            main_key::ATTRIBUTES_COVERAGE,
+            coverage,
+        )
+    }
+
+    pub(crate) fn delete_attributes_coverage(&self, txn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(txn, main_key::ATTRIBUTES_COVERAGE)
+    }
+
     pub fn max_values_per_facet(&self, txn: &RoTxn) -> heed::Result<Option<usize>> {
         self.main.get::<_, Str, OwnedType<usize>>(txn, main_key::MAX_VALUES_PER_FACET)
     }
@@ -1195,6 +1561,52 @@ impl Index {
         self.main.delete::<_, Str>(txn, main_key::PAGINATION_MAX_TOTAL_HITS)
     }
 
+    /// The maximum number of characters of the indexed and searched words,
+    /// the longer words are truncated. `None` if the words are not truncated.
+    pub fn max_token_length(&self, txn: &RoTxn) -> heed::Result<Option<usize>> {
+        self.main.get::<_, Str, OwnedType<usize>>(txn, main_key::MAX_TOKEN_LENGTH)
+    }
+
+    pub(crate) fn put_max_token_length(&self, txn: &mut RwTxn, val: usize) -> heed::Result<()> {
+        self.main.put::<_, Str, OwnedType<usize>>(txn, main_key::MAX_TOKEN_LENGTH, &val)
+    }
+
+    pub(crate) fn delete_max_token_length(&self, txn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(txn, main_key::MAX_TOKEN_LENGTH)
+    }
+
+    /// The maximum length, in bytes, of the document identifiers,
+    /// `None` if the default [`crate::DEFAULT_MAX_DOCUMENT_ID_LENGTH`] applies.
+    pub fn max_document_id_length(&self, txn: &RoTxn) -> heed::Result<Option<usize>> {
+        self.main.get::<_, Str, OwnedType<usize>>(txn, main_key::MAX_DOCUMENT_ID_LENGTH)
+    }
+
+    pub(crate) fn put_max_document_id_length(
+        &self,
+        txn: &mut RwTxn,
+        val: usize,
+    ) -> heed::Result<()> {
+        self.main.put::<_, Str, OwnedType<usize>>(txn, main_key::MAX_DOCUMENT_ID_LENGTH, &val)
+    }
+
+    pub(crate) fn delete_max_document_id_length(&self, txn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(txn, main_key::MAX_DOCUMENT_ID_LENGTH)
+    }
+
+    /// The number of documents the exact query must match to skip the typo and prefix
+    /// derivations of the query words, `None` if the derivations are always searched.
+    pub fn min_exact_results(&self, txn: &RoTxn) -> heed::Result<Option<usize>> {
+        self.main.get::<_, Str, OwnedType<usize>>(txn, main_key::MIN_EXACT_RESULTS)
+    }
+
+    pub(crate) fn put_min_exact_results(&self, txn: &mut RwTxn, val: usize) -> heed::Result<()> {
+        self.main.put::<_, Str, OwnedType<usize>>(txn, main_key::MIN_EXACT_RESULTS, &val)
+    }
+
+    pub(crate) fn delete_min_exact_results(&self, txn: &mut RwTxn) -> heed::Result<bool> {
+        self.main.delete::<_, Str>(txn, main_key::MIN_EXACT_RESULTS)
+    }
+
     /* script  language docids */
     /// Retrieve all the documents ids that correspond with (Script, Language) key, `None` if it is any.
     pub fn script_language_documents_ids(
@@ -1237,26 +1649,126 @@ impl Index {
 
         Ok(script_language)
     }
+
+    /// Cross-checks the postings of the words with the documents of the index,
+    /// see [`IntegrityReport`] for the discrepancies reported.
+    pub fn verify_integrity(&self, rtxn: &RoTxn) -> Result<IntegrityReport> {
+        // The soft deleted documents are still part of the postings.
+        let existing_docids = self.documents_ids(rtxn)? | self.soft_deleted_documents_ids(rtxn)?;
+
+        // The documents containing each word, according to the documents themselves.
+        let mut missing_postings: BTreeMap<String, RoaringBitmap> = BTreeMap::new();
+        for result in self.docid_word_positions.remap_data_type::<DecodeIgnore>().iter(rtxn)? {
+            let ((docid, word), ()) = result?;
+            match missing_postings.get_mut(word) {
+                Some(docids) => {
+                    docids.insert(docid);
+                }
+                None => {
+                    missing_postings.insert(word.to_string(), std::iter::once(docid).collect());
+                }
+            }
+        }
+
+        // Each posting is read once, the indexed documents are removed from the documents
+        // containing the word and only the missing ones are left.
+        let mut report = IntegrityReport::default();
+        for database in [self.word_docids, self.exact_word_docids] {
+            for result in database.iter(rtxn)? {
+                let (word, docids) = result?;
+                if let Some(missing) = missing_postings.get_mut(word) {
+                    *missing -= &docids;
+                }
+                let dangling = docids - &existing_docids;
+                if !dangling.is_empty() {
+                    *report.dangling_postings.entry(word.to_string()).or_default() |= dangling;
+                }
+            }
+        }
+
+        missing_postings.retain(|_, docids| !docids.is_empty());
+        report.missing_postings = missing_postings;
+
+        Ok(report)
+    }
+
+    /// Counts the documents stored in the index and compares
+    /// this number to the one returned by [`Index::number_of_documents`].
+    pub fn consistency_check(&self, rtxn: &RoTxn) -> Result<ConsistencyReport> {
+        let number_of_documents = self.number_of_documents(rtxn)?;
+        // The soft deleted documents are still stored but must not be counted.
+        let soft_deleted_docids = self.soft_deleted_documents_ids(rtxn)?;
+
+        let mut stored_documents = 0;
+        for result in self.documents.remap_data_type::<DecodeIgnore>().iter(rtxn)? {
+            let (docid, ()) = result?;
+            if !soft_deleted_docids.contains(docid.get()) {
+                stored_documents += 1;
+            }
+        }
+
+        Ok(ConsistencyReport { number_of_documents, stored_documents })
+    }
+}
+
+/// The result of [`Index::consistency_check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsistencyReport {
+    /// The number of documents according to the documents ids of the index.
+    pub number_of_documents: u64,
+    /// The number of documents actually stored in the index.
+    pub stored_documents: u64,
+}
+
+impl ConsistencyReport {
+    /// Returns `true` if both numbers of documents are equal.
+    pub fn is_consistent(&self) -> bool {
+        self.number_of_documents == self.stored_documents
+    }
+}
+
+/// The discrepancies between the postings and the documents of an index,
+/// returned by [`Index::verify_integrity`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IntegrityReport {
+    /// The words whose postings reference documents that are not part of the index.
+    pub dangling_postings: BTreeMap<String, RoaringBitmap>,
+    /// The words contained in documents that are missing from the postings of these words.
+    pub missing_postings: BTreeMap<String, RoaringBitmap>,
+}
+
+impl IntegrityReport {
+    /// Returns `true` if no discrepancy was found.
+    pub fn is_empty(&self) -> bool {
+        self.dangling_postings.is_empty() && self.missing_postings.is_empty()
+    }
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
     use std::collections::HashSet;
+    use std::iter::FromIterator;
     use std::ops::Deref;
 
     use big_s::S;
     use heed::{EnvOpenOptions, RwTxn};
-    use maplit::hashset;
+    use maplit::{btreemap, hashset};
+    use roaring::RoaringBitmap;
     use tempfile::TempDir;
 
     use crate::documents::DocumentsBatchReader;
     use crate::error::{Error, InternalError};
-    use crate::index::{DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS};
+    use crate::index::{
+        ConsistencyReport, IntegrityReport, DEFAULT_MIN_WORD_LEN_ONE_TYPO,
+        DEFAULT_MIN_WORD_LEN_TWO_TYPOS,
+    };
     use crate::update::{
         self, DeleteDocuments, DeletionStrategy, IndexDocuments, IndexDocumentsConfig,
         IndexDocumentsMethod, IndexerConfig, Settings,
     };
-    use crate::{db_snap, obkv_to_json, Filter, Index, Search, SearchResult};
+    use crate::{
+        db_snap, obkv_to_json, relative_from_absolute_position, Filter, Index, Search, SearchResult,
+    };
 
     pub(crate) struct TempIndex {
         pub inner: Index,
@@ -2485,6 +2997,74 @@ pub(crate) mod tests {
         db_snap!(index, geo_faceted_documents_ids); // ensure that no more document was inserted
     }
 
+    #[test]
+    fn verify_integrity() {
+        let mut index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "hello world" },
+                { "id": 1, "text": "hello kitty" },
+                { "id": 2, "text": "goodbye world" },
+                { "id": 3, "text": "goodbye kitty" },
+            ]))
+            .unwrap();
+
+        index.index_documents_config.deletion_strategy = DeletionStrategy::AlwaysSoft;
+        index.delete_document("1");
+        index.index_documents_config.deletion_strategy = DeletionStrategy::AlwaysHard;
+        index.delete_document("2");
+
+        let mut wtxn = index.write_txn().unwrap();
+        assert_eq!(index.verify_integrity(&wtxn).unwrap(), IntegrityReport::default());
+
+        // We corrupt the postings of the word "hello" by adding
+        // a document that doesn't exist and removing one that does.
+        let mut docids = index.word_docids.get(&wtxn, "hello").unwrap().unwrap();
+        docids.insert(42);
+        docids.remove(0);
+        index.word_docids.put(&mut wtxn, "hello", &docids).unwrap();
+
+        let report = index.verify_integrity(&wtxn).unwrap();
+        assert!(!report.is_empty());
+        assert_eq!(
+            report.dangling_postings,
+            btreemap! { S("hello") => RoaringBitmap::from_iter([42]) }
+        );
+        assert_eq!(
+            report.missing_postings,
+            btreemap! { S("hello") => RoaringBitmap::from_iter([0]) }
+        );
+    }
+
+    #[test]
+    fn consistency_check() {
+        let mut index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "hello" },
+                { "id": 1, "text": "kitty" },
+                { "id": 2, "text": "world" },
+            ]))
+            .unwrap();
+
+        index.index_documents_config.deletion_strategy = DeletionStrategy::AlwaysSoft;
+        index.delete_document("1");
+
+        let mut wtxn = index.write_txn().unwrap();
+        let report = index.consistency_check(&wtxn).unwrap();
+        assert!(report.is_consistent());
+        assert_eq!(report.stored_documents, 2);
+
+        // We corrupt the documents ids by forgetting one of the documents.
+        let mut documents_ids = index.documents_ids(&wtxn).unwrap();
+        documents_ids.remove(0);
+        index.put_documents_ids(&mut wtxn, &documents_ids).unwrap();
+
+        let report = index.consistency_check(&wtxn).unwrap();
+        assert!(!report.is_consistent());
+        assert_eq!(report, ConsistencyReport { number_of_documents: 1, stored_documents: 2 });
+    }
+
     #[test]
     fn unexpected_extra_fields_in_geo_field() {
         let index = TempIndex::new();
@@ -2515,4 +3095,347 @@ pub(crate) mod tests {
 
         db_snap!(index, geo_faceted_documents_ids); // ensure that no documents were inserted
     }
+
+    #[test]
+    fn documents_raw_array() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "name": "kevin" },
+                { "id": 1, "name": "bob", "age": 20 },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        // the internal id 2 doesn't exist and must be skipped
+        let array = index.documents_raw_array(&rtxn, [0, 2, 1]).unwrap();
+        let array: serde_json::Value = serde_json::from_slice(&array).unwrap();
+        assert_eq!(
+            array,
+            serde_json::json!([
+                { "id": 0, "name": "kevin" },
+                { "id": 1, "name": "bob", "age": 20 },
+            ])
+        );
+
+        let array = index.documents_raw_array(&rtxn, [2]).unwrap();
+        assert_eq!(array, b"[]");
+    }
+
+    #[test]
+    fn document() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Movie {
+            id: u32,
+            title: String,
+        }
+
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_displayed_fields(vec![S("id"), S("title")]);
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "Carol", "budget": 11_800_000 },
+                { "id": 1, "title": "Wonder Woman", "budget": 149_000_000 },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let movie: Option<Movie> = index.document(&rtxn, "1").unwrap();
+        assert_eq!(movie, Some(Movie { id: 1, title: S("Wonder Woman") }));
+
+        let movie: Option<Movie> = index.document(&rtxn, "2").unwrap();
+        assert_eq!(movie, None);
+    }
+
+    #[test]
+    fn documents_where() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct User {
+            id: u32,
+            active: bool,
+        }
+
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("active") });
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "active": true },
+                { "id": 1, "active": false },
+                { "id": 2, "active": true },
+                { "id": 3, "active": true },
+                { "id": 4, "active": false },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let filter = Filter::from_str("active = true").unwrap().unwrap();
+        let (users, total): (Vec<User>, _) = index.documents_where(&rtxn, &filter, 0, 10).unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(
+            users,
+            vec![
+                User { id: 0, active: true },
+                User { id: 2, active: true },
+                User { id: 3, active: true },
+            ]
+        );
+
+        let (users, total): (Vec<User>, _) = index.documents_where(&rtxn, &filter, 1, 1).unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(users, vec![User { id: 2, active: true }]);
+    }
+
+    #[test]
+    fn more_like_this() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "rust borrow checker lifetimes" },
+                { "id": 1, "text": "the borrow checker and the lifetimes of rust" },
+                { "id": 2, "text": "cooking pasta with tomatoes" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let similar = index.more_like_this(&rtxn, 0, 10).unwrap();
+        assert_eq!(similar.first(), Some(&1));
+        assert!(!similar.contains(&0));
+
+        let similar = index.more_like_this(&rtxn, 0, 0).unwrap();
+        assert!(similar.is_empty());
+    }
+
+    #[test]
+    fn more_like_this_unknown_document() {
+        use crate::error::UserError;
+
+        let mut index = TempIndex::new();
+        index.index_documents_config.deletion_strategy = DeletionStrategy::AlwaysSoft;
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "rust borrow checker lifetimes" },
+                { "id": 1, "text": "the borrow checker and the lifetimes of rust" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let error = index.more_like_this(&rtxn, 42, 10).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UserError(UserError::UnknownInternalDocumentId { document_id: 42 })
+        ));
+        drop(rtxn);
+
+        index.delete_document("1");
+
+        let rtxn = index.read_txn().unwrap();
+        let error = index.more_like_this(&rtxn, 1, 10).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UserError(UserError::AccessingSoftDeletedDocument { document_id: 1 })
+        ));
+    }
+
+    #[test]
+    fn explain_query() {
+        use maplit::hashmap;
+
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_synonyms(hashmap! {
+                    "hello".to_string() => vec!["good morning".to_string()],
+                });
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "hello world" },
+                { "id": 1, "text": "worldwide wordle" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let explanation = index.explain_query(&rtxn, "Hello wor").unwrap();
+        assert!(explanation.phrases.is_empty());
+
+        assert_eq!(explanation.words.len(), 2);
+        let (hello, wor) = (&explanation.words[0], &explanation.words[1]);
+        assert_eq!(hello.word, "hello");
+        assert!(!hello.prefix);
+        assert_eq!(hello.synonyms, vec![vec!["good".to_string(), "morning".to_string()]]);
+        assert!(hello.prefixes.is_empty());
+
+        assert_eq!(wor.word, "wor");
+        assert!(wor.prefix);
+        assert_eq!(wor.prefixes, vec!["wordle", "world", "worldwide"]);
+    }
+
+    #[test]
+    fn explain_query_typo_prefix_and_ngrams() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "programming program" },
+                { "id": 1, "text": "pragramming" },
+                { "id": 2, "text": "sunflower" },
+                { "id": 3, "text": "sun flower" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let explanation = index.explain_query(&rtxn, "progra").unwrap();
+        let progra = &explanation.words[0];
+        assert!(progra.prefix);
+        // the prefix is searched with typos too, so are the words starting with a typo variant.
+        assert!(progra.typos.contains(&("pragramming".to_string(), 1)), "{:?}", progra.typos);
+        assert_eq!(progra.prefixes, vec!["program", "programming"]);
+
+        let explanation = index.explain_query(&rtxn, "sun flower").unwrap();
+        assert_eq!(explanation.ngrams.len(), 1);
+        assert_eq!(explanation.ngrams[0].word, "sunflower");
+        assert!(explanation.ngrams[0].prefix);
+
+        let explanation = index.explain_query(&rtxn, "sunflower").unwrap();
+        assert_eq!(explanation.words[0].split, Some(("sun".to_string(), "flower".to_string())));
+        drop(rtxn);
+
+        index
+            .update_settings(|settings| {
+                settings.set_autorize_typos(false);
+            })
+            .unwrap();
+
+        // Without typos only the exact prefixes are searched.
+        let rtxn = index.read_txn().unwrap();
+        let explanation = index.explain_query(&rtxn, "progra").unwrap();
+        assert!(explanation.words[0].typos.is_empty(), "{:?}", explanation.words[0].typos);
+        assert_eq!(explanation.words[0].prefixes, vec!["program", "programming"]);
+
+        // The words beyond the words limit of the search are ignored.
+        let explanation = index.explain_query(&rtxn, "a b c d e f g h i j k l").unwrap();
+        assert_eq!(explanation.words.len(), 10);
+    }
+
+    #[test]
+    fn min_exact_results_skips_the_derivations() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_min_exact_results(2);
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "apple pie" },
+                { "id": 1, "text": "apple juice" },
+                { "id": 2, "text": "apple tart" },
+                { "id": 3, "text": "applesauce" },
+                { "id": 4, "text": "banana split" },
+                { "id": 5, "text": "bananas foster" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+
+        // the exact query matches enough documents, the prefix isn't searched.
+        let explanation = index.explain_query(&rtxn, "apple").unwrap();
+        assert!(explanation.exact_only);
+        assert!(!explanation.words[0].prefix);
+        assert!(explanation.words[0].prefixes.is_empty());
+        let mut documents_ids = index.search(&rtxn).query("apple").execute().unwrap().documents_ids;
+        documents_ids.sort_unstable();
+        assert_eq!(documents_ids, vec![0, 1, 2]);
+
+        // a rare query is expanded.
+        let explanation = index.explain_query(&rtxn, "banana").unwrap();
+        assert!(!explanation.exact_only);
+        assert_eq!(explanation.words[0].prefixes, vec!["bananas"]);
+        let mut documents_ids =
+            index.search(&rtxn).query("banana").execute().unwrap().documents_ids;
+        documents_ids.sort_unstable();
+        assert_eq!(documents_ids, vec![4, 5]);
+    }
+
+    #[test]
+    fn postings() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "hello world hello" },
+                { "id": 1, "text": "goodbye" },
+                { "id": 2, "text": "world" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let postings: Vec<_> = index
+            .postings(&rtxn, "world")
+            .unwrap()
+            .into_iter()
+            .map(|(docid, positions)| {
+                let positions: Vec<_> =
+                    positions.into_iter().map(|p| relative_from_absolute_position(p).1).collect();
+                (docid, positions)
+            })
+            .collect();
+        assert_eq!(postings, vec![(0, vec![1]), (2, vec![0])]);
+
+        let postings = index.postings(&rtxn, "hello").unwrap();
+        let positions: Vec<_> =
+            postings[0].1.iter().map(|p| relative_from_absolute_position(*p).1).collect();
+        assert_eq!(positions, vec![0, 2]);
+
+        assert!(index.postings(&rtxn, "missing").unwrap().is_empty());
+    }
+
+    #[test]
+    fn suggest_correction() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "the best restaurant in town" },
+                { "id": 1, "text": "a restaurant by the sea" },
+                { "id": 2, "text": "restaurant menus" },
+                { "id": 3, "text": "a restaurants guide" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let suggestion = index.suggest_correction(&rtxn, "best resturant").unwrap();
+        assert_eq!(suggestion.as_deref(), Some("best restaurant"));
+
+        // there is nothing to correct
+        assert_eq!(index.suggest_correction(&rtxn, "restaurant menus").unwrap(), None);
+        assert_eq!(index.suggest_correction(&rtxn, "xyzzy").unwrap(), None);
+        drop(rtxn);
+
+        // the long words are truncated like the indexed ones and are known.
+        index
+            .update_settings(|settings| {
+                settings.set_max_token_length(5);
+            })
+            .unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.suggest_correction(&rtxn, "restaurantz").unwrap(), None);
+        drop(rtxn);
+
+        // nothing is suggested when the typos are disabled.
+        index
+            .update_settings(|settings| {
+                settings.reset_max_token_length();
+                settings.set_autorize_typos(false);
+            })
+            .unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.suggest_correction(&rtxn, "best resturant").unwrap(), None);
+    }
 }
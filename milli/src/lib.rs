@@ -41,11 +41,12 @@ pub use self::heed_codec::{
     CboRoaringBitmapLenCodec, FieldIdWordCountCodec, ObkvCodec, RoaringBitmapCodec,
     RoaringBitmapLenCodec, StrBEU32Codec, U8StrStrCodec, UncheckedU8StrStrCodec,
 };
-pub use self::index::Index;
+pub use self::index::{ConsistencyReport, Index, IntegrityReport};
 pub use self::search::{
-    CriterionImplementationStrategy, FacetDistribution, Filter, FormatOptions, MatchBounds,
-    MatcherBuilder, MatchingWord, MatchingWords, Search, SearchResult, TermsMatchingStrategy,
-    DEFAULT_VALUES_PER_FACET,
+    CriterionImplementationStrategy, FacetDistribution, Filter, FormatOptions, HighlightMode,
+    MatchBounds, MatcherBuilder, MatchingWord, MatchingWords, NullPolicy, PrimitiveQueryPart,
+    QueryAst, QueryExplanation, Search, SearchOptions, SearchResult, TermsMatchingStrategy,
+    WordExplanation, DEFAULT_VALUES_PER_FACET,
 };
 
 pub type Result<T> = std::result::Result<T, error::Error>;
@@ -89,6 +90,23 @@ pub const MAX_WORD_LENGTH: usize = MAX_LMDB_KEY_LENGTH / 2;
 
 pub const MAX_POSITION_PER_ATTRIBUTE: u32 = u16::MAX as u32 + 1;
 
+/// The maximum length, in bytes, a document identifier can be when
+/// the `max_document_id_length` setting isn't set.
+pub const DEFAULT_MAX_DOCUMENT_ID_LENGTH: usize = 512;
+
+/// The reserved field holding the boost of a document, the multiplier of its relevancy.
+///
+/// Documents without this field have a boost of 1.0.
+pub const RESERVED_BOOST_FIELD_NAME: &str = "_boost";
+
+/// Truncates the word to its first `max_length` characters.
+pub fn truncate_word(word: &str, max_length: usize) -> &str {
+    match word.char_indices().nth(max_length) {
+        Some((index, _)) => &word[..index],
+        None => word,
+    }
+}
+
 // Convert an absolute word position into a relative position.
 // Return the field id of the attribute related to the absolute position
 // and the relative position in the attribute.
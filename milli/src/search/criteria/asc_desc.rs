@@ -1,4 +1,4 @@
-use std::mem::take;
+use std::mem::{replace, take};
 
 use heed::BytesDecode;
 use itertools::Itertools;
@@ -26,6 +26,7 @@ pub struct AscDesc<'t> {
     field_name: String,
     field_id: Option<FieldId>,
     is_ascending: bool,
+    nulls_first: bool,
     query_tree: Option<Operation>,
     candidates: Box<dyn Iterator<Item = heed::Result<RoaringBitmap>> + 't>,
     allowed_candidates: RoaringBitmap,
@@ -41,9 +42,10 @@ impl<'t> AscDesc<'t> {
         rtxn: &'t heed::RoTxn,
         parent: Box<dyn Criterion + 't>,
         field_name: String,
+        nulls_first: bool,
         implementation_strategy: CriterionImplementationStrategy,
     ) -> Result<Self> {
-        Self::new(index, rtxn, parent, field_name, true, implementation_strategy)
+        Self::new(index, rtxn, parent, field_name, true, nulls_first, implementation_strategy)
     }
 
     pub fn desc(
@@ -51,9 +53,10 @@ impl<'t> AscDesc<'t> {
         rtxn: &'t heed::RoTxn,
         parent: Box<dyn Criterion + 't>,
         field_name: String,
+        nulls_first: bool,
         implementation_strategy: CriterionImplementationStrategy,
     ) -> Result<Self> {
-        Self::new(index, rtxn, parent, field_name, false, implementation_strategy)
+        Self::new(index, rtxn, parent, field_name, false, nulls_first, implementation_strategy)
     }
 
     fn new(
@@ -62,6 +65,7 @@ impl<'t> AscDesc<'t> {
         parent: Box<dyn Criterion + 't>,
         field_name: String,
         is_ascending: bool,
+        nulls_first: bool,
         implementation_strategy: CriterionImplementationStrategy,
     ) -> Result<Self> {
         let fields_ids_map = index.fields_ids_map(rtxn)?;
@@ -83,6 +87,7 @@ impl<'t> AscDesc<'t> {
             field_name,
             field_id,
             is_ascending,
+            nulls_first,
             query_tree: None,
             candidates: Box::new(std::iter::empty()),
             allowed_candidates: RoaringBitmap::new(),
@@ -149,6 +154,7 @@ impl<'t> Criterion for AscDesc<'t> {
                         }
 
                         self.allowed_candidates = &candidates - params.excluded_candidates;
+                        let nulls = &candidates - &self.faceted_candidates;
                         self.candidates = match self.field_id {
                             Some(field_id) => facet_ordered(
                                 self.index,
@@ -160,6 +166,12 @@ impl<'t> Criterion for AscDesc<'t> {
                             )?,
                             None => Box::new(std::iter::empty()),
                         };
+                        // the documents without a value are otherwise returned once all the values are exhausted.
+                        if self.nulls_first && !nulls.is_empty() {
+                            let values =
+                                replace(&mut self.candidates, Box::new(std::iter::empty()));
+                            self.candidates = Box::new(std::iter::once(Ok(nulls)).chain(values));
+                        }
                     }
                     None => return Ok(None),
                 },
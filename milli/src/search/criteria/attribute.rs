@@ -1,6 +1,6 @@
 use std::cmp::{self, Ordering};
 use std::collections::binary_heap::PeekMut;
-use std::collections::{btree_map, BTreeMap, BinaryHeap, HashMap};
+use std::collections::{btree_map, BTreeMap, BinaryHeap, HashMap, HashSet};
 use std::iter::Peekable;
 use std::mem::take;
 
@@ -12,7 +12,7 @@ use crate::search::query_tree::{Operation, QueryKind};
 use crate::search::{
     build_dfa, word_derivations, CriterionImplementationStrategy, WordDerivationsCache,
 };
-use crate::Result;
+use crate::{absolute_from_relative_position, relative_from_absolute_position, FieldId, Result};
 
 /// To be able to divide integers by the number of words in the query
 /// we want to find a multiplier that allow us to divide by any number between 1 and 10.
@@ -23,6 +23,10 @@ const LCM_10_FIRST_NUMBERS: u32 = 2520;
 /// the system to choose between one algorithm or another.
 const CANDIDATES_THRESHOLD: u64 = 500;
 
+/// Penalty added to the position of a word that is only found in fields
+/// that do not reach their configured attributes coverage.
+const UNCOVERED_FIELD_PENALTY: u64 = 1 << 32;
+
 type FlattenedQueryTree = Vec<Vec<Vec<Query>>>;
 
 pub struct Attribute<'t> {
@@ -72,14 +76,16 @@ impl<'t> Criterion for Attribute<'t> {
                     }));
                 }
                 Some((query_tree, flattened_query_tree, mut allowed_candidates)) => {
+                    // the attributes coverage and order are only supported by the linear buckets.
                     let found_candidates = if matches!(
                         self.implementation_strategy,
                         CriterionImplementationStrategy::OnlyIterative
-                    ) || (matches!(
-                        self.implementation_strategy,
-                        CriterionImplementationStrategy::Dynamic
-                    ) && allowed_candidates.len()
-                        < CANDIDATES_THRESHOLD)
+                    ) || !self.ctx.fields_coverage().is_empty()
+                        || !self.ctx.fields_order().is_empty()
+                        || (matches!(
+                            self.implementation_strategy,
+                            CriterionImplementationStrategy::Dynamic
+                        ) && allowed_candidates.len() < CANDIDATES_THRESHOLD)
                     {
                         let linear_buckets = match self.linear_buckets.as_mut() {
                             Some(linear_buckets) => linear_buckets,
@@ -477,61 +483,102 @@ fn initialize_linear_buckets(
     fn compute_candidate_rank(
         branches: &FlattenedQueryTree,
         words_positions: HashMap<String, RoaringBitmap>,
+        fields_coverage: &HashMap<FieldId, usize>,
+        fields_order: &HashMap<FieldId, FieldId>,
     ) -> u64 {
+        // moves the position in the field ranked by the attribute order of the query.
+        let reorder = |pos: u32| -> u64 {
+            let (fid, relative) = relative_from_absolute_position(pos);
+            match fields_order.get(&fid) {
+                Some(rank) => absolute_from_relative_position(*rank, relative) as u64,
+                None => pos as u64,
+            }
+        };
+
         let mut min_rank = u64::max_value();
         for branch in branches {
             let branch_len = branch.len();
-            let mut branch_rank = Vec::with_capacity(branch_len);
+            let mut branch_positions = Vec::with_capacity(branch_len);
             for derivates in branch {
-                let mut position = None;
+                let mut positions = RoaringBitmap::new();
                 for Query { prefix, kind } in derivates {
-                    // find the best position of the current word in the document.
-                    let current_position = match kind {
+                    // find all the positions of the current word in the document.
+                    match kind {
                         QueryKind::Exact { word, .. } => {
                             if *prefix {
                                 word_derivations(word, true, 0, &words_positions)
-                                    .flat_map(|positions| positions.iter().next())
-                                    .min()
-                            } else {
-                                words_positions
-                                    .get(word)
-                                    .and_then(|positions| positions.iter().next())
+                                    .for_each(|p| positions |= p);
+                            } else if let Some(p) = words_positions.get(word) {
+                                positions |= p;
                             }
                         }
                         QueryKind::Tolerant { typo, word } => {
                             word_derivations(word, *prefix, *typo, &words_positions)
-                                .flat_map(|positions| positions.iter().next())
-                                .min()
+                                .for_each(|p| positions |= p);
                         }
-                    };
-
-                    match (position, current_position) {
-                        (Some(p), Some(cp)) => position = Some(cmp::min(p, cp)),
-                        (None, Some(cp)) => position = Some(cp),
-                        _ => (),
                     }
                 }
 
-                // if a position is found, we add it to the branch score,
-                // otherwise the branch is considered as unfindable in this document and we break.
-                if let Some(position) = position {
-                    branch_rank.push(position as u64);
-                } else {
-                    branch_rank.clear();
+                // if no position is found the branch is considered
+                // as unfindable in this document and we break.
+                if positions.is_empty() {
+                    branch_positions.clear();
                     break;
                 }
+                branch_positions.push(positions);
             }
 
-            if !branch_rank.is_empty() {
-                branch_rank.sort_unstable();
-                // because several words in same query can't match all a the position 0,
-                // we substract the word index to the position.
-                let branch_rank: u64 =
-                    branch_rank.into_iter().enumerate().map(|(i, r)| r - i as u64).sum();
-                // here we do the means of the words of the branch
-                min_rank =
-                    min_rank.min(branch_rank * LCM_10_FIRST_NUMBERS as u64 / branch_len as u64);
+            if branch_positions.is_empty() {
+                continue;
             }
+
+            // the fields that do not contain enough words of the branch
+            // are not taken into account to compute the position of the words.
+            let mut excluded_fields = HashSet::new();
+            if !fields_coverage.is_empty() {
+                let mut words_by_field: HashMap<FieldId, usize> = HashMap::new();
+                for positions in &branch_positions {
+                    let fields: HashSet<FieldId> = positions
+                        .iter()
+                        .map(|pos| relative_from_absolute_position(pos).0)
+                        .collect();
+                    for fid in fields {
+                        *words_by_field.entry(fid).or_default() += 1;
+                    }
+                }
+                for (fid, coverage) in fields_coverage {
+                    let count = words_by_field.get(fid).copied().unwrap_or_default();
+                    if count < cmp::min(*coverage, branch_len) {
+                        excluded_fields.insert(*fid);
+                    }
+                }
+            }
+
+            let mut branch_rank: Vec<u64> = branch_positions
+                .iter()
+                .map(|positions| {
+                    positions
+                        .iter()
+                        .filter(|pos| {
+                            !excluded_fields.contains(&relative_from_absolute_position(*pos).0)
+                        })
+                        .map(reorder)
+                        .min()
+                        // a word only found in excluded fields is ranked after all the others.
+                        .unwrap_or_else(|| {
+                            positions.iter().map(reorder).min().unwrap_or_default()
+                                + UNCOVERED_FIELD_PENALTY
+                        })
+                })
+                .collect();
+
+            branch_rank.sort_unstable();
+            // because several words in same query can't match all a the position 0,
+            // we substract the word index to the position.
+            let branch_rank: u64 =
+                branch_rank.into_iter().enumerate().map(|(i, r)| r - i as u64).sum();
+            // here we do the means of the words of the branch
+            min_rank = min_rank.min(branch_rank * LCM_10_FIRST_NUMBERS as u64 / branch_len as u64);
         }
 
         min_rank
@@ -556,7 +603,12 @@ fn initialize_linear_buckets(
     let mut candidates = BTreeMap::new();
     for docid in allowed_candidates {
         let words_positions = ctx.docid_words_positions(docid)?;
-        let rank = compute_candidate_rank(branches, words_positions);
+        let rank = compute_candidate_rank(
+            branches,
+            words_positions,
+            ctx.fields_coverage(),
+            ctx.fields_order(),
+        );
         candidates.entry(rank).or_insert_with(RoaringBitmap::new).insert(docid);
     }
 
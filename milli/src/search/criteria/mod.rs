@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::mem::take;
 use std::ops::{BitOr, BitOrAssign};
 
@@ -18,7 +18,10 @@ use super::CriterionImplementationStrategy;
 use crate::search::criteria::geo::Geo;
 use crate::search::{word_derivations, Distinct, WordDerivationsCache};
 use crate::update::{MAX_LENGTH_FOR_PREFIX_PROXIMITY_DB, MAX_PROXIMITY_FOR_PREFIX_PROXIMITY_DB};
-use crate::{AscDesc as AscDescName, DocumentId, FieldId, Index, Member, Result};
+use crate::{
+    relative_from_absolute_position, AscDesc as AscDescName, DocumentId, FieldId, Index, Member,
+    Result,
+};
 
 mod asc_desc;
 pub use asc_desc::{facet_max_value, facet_min_value};
@@ -180,6 +183,12 @@ pub trait Context<'c> {
         word_count: u8,
     ) -> heed::Result<Option<RoaringBitmap>>;
     fn word_position_docids(&self, word: &str, pos: u32) -> heed::Result<Option<RoaringBitmap>>;
+    /// Returns the fields in which the last word of the query is not matched as a prefix.
+    fn non_prefix_fields_ids(&self) -> &HashSet<FieldId>;
+    /// Returns the minimum number of query words that must match in the fields.
+    fn fields_coverage(&self) -> &HashMap<FieldId, usize>;
+    /// Returns the rank of the fields when the query overrides the attributes order.
+    fn fields_order(&self) -> &HashMap<FieldId, FieldId>;
 }
 
 pub struct CriteriaBuilder<'t> {
@@ -187,6 +196,10 @@ pub struct CriteriaBuilder<'t> {
     index: &'t Index,
     words_fst: fst::Set<Cow<'t, [u8]>>,
     words_prefixes_fst: fst::Set<Cow<'t, [u8]>>,
+    non_prefix_fields_ids: HashSet<FieldId>,
+    fields_coverage: HashMap<FieldId, usize>,
+    fields_order: HashMap<FieldId, FieldId>,
+    sort_nulls_first: bool,
 }
 
 /// Return the docids for the following word pairs and proximities using [`Context::word_pair_proximity_docids`].
@@ -363,13 +376,72 @@ impl<'c> Context<'c> for CriteriaBuilder<'c> {
         let key = (word, pos);
         self.index.word_position_docids.get(self.rtxn, &key)
     }
+
+    fn non_prefix_fields_ids(&self) -> &HashSet<FieldId> {
+        &self.non_prefix_fields_ids
+    }
+
+    fn fields_coverage(&self) -> &HashMap<FieldId, usize> {
+        &self.fields_coverage
+    }
+
+    fn fields_order(&self) -> &HashMap<FieldId, FieldId> {
+        &self.fields_order
+    }
 }
 
 impl<'t> CriteriaBuilder<'t> {
     pub fn new(rtxn: &'t heed::RoTxn<'t>, index: &'t Index) -> Result<Self> {
         let words_fst = index.words_fst(rtxn)?;
         let words_prefixes_fst = index.words_prefixes_fst(rtxn)?;
-        Ok(Self { rtxn, index, words_fst, words_prefixes_fst })
+        let non_prefix_fields_ids = index.non_prefix_attributes_ids(rtxn)?;
+        let fields_coverage = index.attributes_coverage_ids(rtxn)?;
+        Ok(Self {
+            rtxn,
+            index,
+            words_fst,
+            words_prefixes_fst,
+            non_prefix_fields_ids,
+            fields_coverage,
+            fields_order: HashMap::new(),
+            sort_nulls_first: false,
+        })
+    }
+
+    /// Returns the documents without a value for a sorted field before the others,
+    /// in both directions, instead of after them.
+    pub fn sort_nulls_first(&mut self, sort_nulls_first: bool) {
+        self.sort_nulls_first = sort_nulls_first;
+    }
+
+    /// Ranks the given attributes first in the attribute ranking rule, in this order.
+    pub fn attribute_order(&mut self, attributes: &[String]) -> Result<()> {
+        let fields_ids_map = self.index.fields_ids_map(self.rtxn)?;
+        let mut ordered_fields = Vec::new();
+        for fid in attributes.iter().filter_map(|name| fields_ids_map.id(name)) {
+            if !ordered_fields.contains(&fid) {
+                ordered_fields.push(fid);
+            }
+        }
+
+        if ordered_fields.is_empty() {
+            self.fields_order.clear();
+            return Ok(());
+        }
+
+        // the attributes that are not listed keep their order after the listed ones.
+        for (fid, _) in fields_ids_map.iter() {
+            if !ordered_fields.contains(&fid) {
+                ordered_fields.push(fid);
+            }
+        }
+        self.fields_order = ordered_fields
+            .into_iter()
+            .enumerate()
+            .map(|(rank, fid)| (fid, rank as FieldId))
+            .collect();
+
+        Ok(())
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -407,6 +479,7 @@ impl<'t> CriteriaBuilder<'t> {
                                     self.rtxn,
                                     criterion,
                                     field.to_string(),
+                                    self.sort_nulls_first,
                                     implementation_strategy,
                                 )?),
                                 AscDescName::Desc(Member::Field(field)) => Box::new(AscDesc::desc(
@@ -414,6 +487,7 @@ impl<'t> CriteriaBuilder<'t> {
                                     self.rtxn,
                                     criterion,
                                     field.to_string(),
+                                    self.sort_nulls_first,
                                     implementation_strategy,
                                 )?),
                                 AscDescName::Asc(Member::Geo(point)) => {
@@ -440,6 +514,7 @@ impl<'t> CriteriaBuilder<'t> {
                     self.rtxn,
                     criterion,
                     field,
+                    false,
                     implementation_strategy,
                 )?),
                 Name::Desc(field) => Box::new(AscDesc::desc(
@@ -447,6 +522,7 @@ impl<'t> CriteriaBuilder<'t> {
                     self.rtxn,
                     criterion,
                     field,
+                    false,
                     implementation_strategy,
                 )?),
             };
@@ -593,9 +669,27 @@ fn query_docids(
     query: &Query,
     wdcache: &mut WordDerivationsCache,
 ) -> Result<RoaringBitmap> {
+    // The words completing the prefix are ignored in the non prefix fields.
+    let restrict_prefix = query.prefix && !ctx.non_prefix_fields_ids().is_empty();
+
     match &query.kind {
         QueryKind::Exact { word, original_typo } => {
-            if query.prefix && ctx.in_prefix_cache(word) {
+            if restrict_prefix {
+                let words = word_derivations(word, true, 0, ctx.words_fst(), wdcache)?;
+                let mut docids = RoaringBitmap::new();
+                for (derived, _typo) in words {
+                    let mut current_docids = ctx.word_docids(derived)?.unwrap_or_default();
+                    // only add the exact docids if the word hasn't been derived
+                    if *original_typo == 0 {
+                        current_docids |= ctx.exact_word_docids(derived)?.unwrap_or_default();
+                    }
+                    if derived != word {
+                        current_docids &= prefix_fields_docids(ctx, derived)?;
+                    }
+                    docids |= current_docids;
+                }
+                Ok(docids)
+            } else if query.prefix && ctx.in_prefix_cache(word) {
                 let mut docids = ctx.word_prefix_docids(word)?.unwrap_or_default();
                 // only add the exact docids if the word hasn't been derived
                 if *original_typo == 0 {
@@ -623,6 +717,14 @@ fn query_docids(
             }
         }
         QueryKind::Tolerant { typo, word } => {
+            // The words reachable with typos only, without completing the prefix.
+            let whole_words: HashSet<_> = if restrict_prefix {
+                let words = word_derivations(word, false, *typo, ctx.words_fst(), wdcache)?;
+                words.iter().map(|(word, _)| word.clone()).collect()
+            } else {
+                HashSet::new()
+            };
+
             let words = word_derivations(word, query.prefix, *typo, ctx.words_fst(), wdcache)?;
             let mut docids = RoaringBitmap::new();
             for (word, typo) in words {
@@ -630,6 +732,9 @@ fn query_docids(
                 if *typo == 0 {
                     current_docids |= ctx.exact_word_docids(word)?.unwrap_or_default()
                 }
+                if restrict_prefix && !whole_words.contains(word) {
+                    current_docids &= prefix_fields_docids(ctx, word)?;
+                }
                 docids |= current_docids;
             }
             Ok(docids)
@@ -637,6 +742,20 @@ fn query_docids(
     }
 }
 
+/// Returns the documents containing the word in a field where the prefixes are matched.
+fn prefix_fields_docids(ctx: &dyn Context, word: &str) -> Result<RoaringBitmap> {
+    let non_prefix_fields_ids = ctx.non_prefix_fields_ids();
+    let mut docids = RoaringBitmap::new();
+    for result in ctx.word_position_iterator(word, false)? {
+        let ((_, position), positions_docids) = result?;
+        let (field_id, _) = relative_from_absolute_position(position);
+        if !non_prefix_fields_ids.contains(&field_id) {
+            docids |= positions_docids;
+        }
+    }
+    Ok(docids)
+}
+
 fn query_pair_proximity_docids(
     ctx: &dyn Context,
     left: &Query,
@@ -816,6 +935,9 @@ pub mod test {
         word_prefix_pair_proximity_docids: HashMap<(String, String, i32), RoaringBitmap>,
         prefix_word_pair_proximity_docids: HashMap<(String, String, i32), RoaringBitmap>,
         docid_words: HashMap<u32, Vec<String>>,
+        non_prefix_fields_ids: HashSet<FieldId>,
+        fields_coverage: HashMap<FieldId, usize>,
+        fields_order: HashMap<FieldId, FieldId>,
     }
 
     impl<'c> Context<'c> for TestContext<'c> {
@@ -927,6 +1049,18 @@ pub mod test {
         ) -> heed::Result<Option<RoaringBitmap>> {
             todo!()
         }
+
+        fn non_prefix_fields_ids(&self) -> &HashSet<FieldId> {
+            &self.non_prefix_fields_ids
+        }
+
+        fn fields_coverage(&self) -> &HashMap<FieldId, usize> {
+            &self.fields_coverage
+        }
+
+        fn fields_order(&self) -> &HashMap<FieldId, FieldId> {
+            &self.fields_order
+        }
     }
 
     impl<'a> Default for TestContext<'a> {
@@ -1043,6 +1177,9 @@ pub mod test {
                 word_prefix_pair_proximity_docids,
                 prefix_word_pair_proximity_docids,
                 docid_words,
+                non_prefix_fields_ids: HashSet::new(),
+                fields_coverage: HashMap::new(),
+                fields_order: HashMap::new(),
             }
         }
     }
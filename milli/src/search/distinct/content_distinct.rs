@@ -0,0 +1,147 @@
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+use roaring::RoaringBitmap;
+
+use super::{Distinct, DocIter};
+use crate::error::UserError;
+use crate::{DocumentId, FieldId, Index, Result, BEU32};
+
+/// A distinct implementer that only keeps one document among the ones having the same content,
+/// on top of the distinct it wraps.
+///
+/// Two documents have the same content when their displayed fields, the primary key excepted,
+/// are equal. The documents are only read when they are iterated: the content of each returned
+/// document is hashed and remembered, and the next documents with an already seen hash are
+/// compared byte by byte with the returned ones before being added to the excluded set.
+pub struct ContentDistinct<'a, D> {
+    inner: D,
+    contents: Contents<'a>,
+    /// The documents already returned, grouped by the hash of their content.
+    seen: Rc<RefCell<HashMap<u64, Vec<DocumentId>>>>,
+}
+
+impl<'a, D> ContentDistinct<'a, D> {
+    pub fn new(inner: D, index: &'a Index, txn: &'a heed::RoTxn<'a>) -> Result<Self> {
+        let displayed_fields = index.displayed_fields_ids(txn)?;
+        let fields_ids_map = index.fields_ids_map(txn)?;
+        let primary_key = index.primary_key(txn)?.and_then(|name| fields_ids_map.id(name));
+
+        let contents = Contents { index, txn, displayed_fields, primary_key };
+        Ok(ContentDistinct { inner, contents, seen: Rc::default() })
+    }
+}
+
+impl<D: Clone> Clone for ContentDistinct<'_, D> {
+    /// The clone doesn't share the seen documents, it only knows the ones returned so far.
+    fn clone(&self) -> Self {
+        ContentDistinct {
+            inner: self.inner.clone(),
+            contents: self.contents.clone(),
+            seen: Rc::new(RefCell::new(self.seen.borrow().clone())),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Contents<'a> {
+    index: &'a Index,
+    txn: &'a heed::RoTxn<'a>,
+    displayed_fields: Option<Vec<FieldId>>,
+    primary_key: Option<FieldId>,
+}
+
+impl<'a> Contents<'a> {
+    /// Returns the displayed fields of the document, the primary key excepted.
+    fn document(&self, docid: DocumentId) -> Result<Vec<(FieldId, &'a [u8])>> {
+        let obkv = self
+            .index
+            .documents
+            .get(self.txn, &BEU32::new(docid))?
+            .ok_or(UserError::UnknownInternalDocumentId { document_id: docid })?;
+
+        Ok(obkv
+            .iter()
+            .filter(|(fid, _)| {
+                let displayed = self.displayed_fields.as_ref().map_or(true, |f| f.contains(fid));
+                displayed && Some(*fid) != self.primary_key
+            })
+            .collect())
+    }
+}
+
+pub struct ContentDistinctIter<'a, I> {
+    inner: I,
+    excluded: RoaringBitmap,
+    contents: Contents<'a>,
+    seen: Rc<RefCell<HashMap<u64, Vec<DocumentId>>>>,
+}
+
+impl<I> ContentDistinctIter<'_, I> {
+    /// Returns whether a document with the same content has already been returned,
+    /// remembers the document otherwise.
+    fn is_duplicate(&self, docid: DocumentId) -> Result<bool> {
+        let content = self.contents.document(docid)?;
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+
+        let mut seen = self.seen.borrow_mut();
+        let docids = seen.entry(hasher.finish()).or_default();
+        // The hashes can collide, the contents must be compared to be sure they are equal.
+        for &other in docids.iter() {
+            if self.contents.document(other)? == content {
+                return Ok(true);
+            }
+        }
+
+        docids.push(docid);
+        Ok(false)
+    }
+}
+
+impl<I: DocIter> Iterator for ContentDistinctIter<'_, I> {
+    type Item = Result<DocumentId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let docid = match self.inner.next()? {
+                Ok(docid) => docid,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if self.excluded.contains(docid) {
+                continue;
+            }
+
+            match self.is_duplicate(docid) {
+                Ok(true) => {
+                    self.excluded.insert(docid);
+                }
+                Ok(false) => return Some(Ok(docid)),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl<I: DocIter> DocIter for ContentDistinctIter<'_, I> {
+    fn into_excluded(self) -> RoaringBitmap {
+        self.inner.into_excluded() | self.excluded
+    }
+}
+
+impl<'a, D: Distinct> Distinct for ContentDistinct<'a, D> {
+    type Iter = ContentDistinctIter<'a, D::Iter>;
+
+    fn distinct(&mut self, candidates: RoaringBitmap, excluded: RoaringBitmap) -> Self::Iter {
+        ContentDistinctIter {
+            inner: self.inner.distinct(candidates, excluded),
+            excluded: RoaringBitmap::new(),
+            contents: self.contents.clone(),
+            seen: self.seen.clone(),
+        }
+    }
+}
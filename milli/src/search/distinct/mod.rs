@@ -1,6 +1,8 @@
+mod content_distinct;
 mod facet_distinct;
 mod noop_distinct;
 
+pub use content_distinct::ContentDistinct;
 pub use facet_distinct::FacetDistinct;
 pub use noop_distinct::NoopDistinct;
 use roaring::RoaringBitmap;
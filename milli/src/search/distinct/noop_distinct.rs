@@ -6,6 +6,7 @@ use crate::{DocumentId, Result};
 
 /// A distinct implementer that does not perform any distinct,
 /// and simply returns an iterator to the candidates.
+#[derive(Clone)]
 pub struct NoopDistinct;
 
 pub struct NoopDistinctIter {
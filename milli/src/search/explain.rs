@@ -0,0 +1,181 @@
+use super::criteria::CriteriaBuilder;
+use super::query_tree::{Operation, PrimitiveQueryPart, Query, QueryKind, QueryTreeBuilder};
+use super::{
+    exact_query_tree, has_enough_exact_results, parse_query, word_derivations,
+    WordDerivationsCache, DEFAULT_WORDS_LIMIT,
+};
+use crate::{Index, Result};
+
+/// How a query is understood by the engine, see [`Index::explain_query`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct QueryExplanation {
+    /// The normalized words of the query, outside of phrases and without the stop words.
+    pub words: Vec<WordExplanation>,
+    /// The normalized words of the quoted phrases, they only match exactly and consecutively.
+    pub phrases: Vec<Vec<String>>,
+    /// The concatenations of two or three consecutive query words, searched as single words.
+    pub ngrams: Vec<WordExplanation>,
+    /// Whether the typo and prefix derivations are skipped because the exact query
+    /// matches at least the minimum exact results of the index.
+    pub exact_only: bool,
+}
+
+/// The alternatives explored by the engine for a single query word.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WordExplanation {
+    /// The normalized word.
+    pub word: String,
+    /// Whether the word is the last one of the query and is matched as a prefix.
+    pub prefix: bool,
+    /// The synonyms of the word, each synonym can be made of multiple words.
+    pub synonyms: Vec<Vec<String>>,
+    /// The words of the index reachable from this word with typos, and their number of typos.
+    /// When the word is a prefix, the words starting with a typo variant of it are listed here.
+    pub typos: Vec<(String, u8)>,
+    /// The words of the index starting with this word, empty when the word isn't a prefix.
+    pub prefixes: Vec<String>,
+    /// The two words the word is split into, searched as a phrase.
+    pub split: Option<(String, String)>,
+}
+
+pub(crate) fn explain_query(
+    index: &Index,
+    rtxn: &heed::RoTxn,
+    query: &str,
+) -> Result<QueryExplanation> {
+    let primitive_query = parse_query(rtxn, index, query, Some(DEFAULT_WORDS_LIMIT))?;
+    // The query tree is built with the same options as the one of a default search.
+    let mut builder = QueryTreeBuilder::new(rtxn, index)?;
+    builder.authorize_typos(index.authorize_typos(rtxn)?);
+    builder.words_limit(DEFAULT_WORDS_LIMIT);
+    let (query_tree, primitive_query) = match builder.build_from_primitive_query(primitive_query)? {
+        Some((query_tree, primitive_query, _)) => (query_tree, primitive_query),
+        None => return Ok(QueryExplanation::default()),
+    };
+
+    let exact_only = match index.min_exact_results(rtxn)? {
+        Some(min_exact_results) => {
+            let ctx = CriteriaBuilder::new(rtxn, index)?;
+            has_enough_exact_results(&ctx, &exact_query_tree(&query_tree), None, min_exact_results)?
+        }
+        None => false,
+    };
+    let query_tree = if exact_only { exact_query_tree(&query_tree) } else { query_tree };
+
+    // We only explain the alternatives that are part of the query tree,
+    // this way they are the ones effectively searched by the engine.
+    let mut queries = Vec::new();
+    let mut tree_phrases = Vec::new();
+    collect_leaves(&query_tree, &mut queries, &mut tree_phrases);
+
+    let words_fst = index.words_fst(rtxn)?;
+    let mut cache = WordDerivationsCache::new();
+    let mut explain_word = |words: &[&str], word: &str, prefix: bool| -> Result<_> {
+        // no word is searched as a prefix when the derivations are skipped.
+        let prefix = prefix && !exact_only;
+        let query = queries.iter().find(|q| q.prefix == prefix && query_word(q) == word);
+        let query = match query {
+            Some(query) => query,
+            None => return Ok(None),
+        };
+
+        // The derivations are computed the same way as when the documents are fetched.
+        let derivations = match &query.kind {
+            QueryKind::Tolerant { typo, word } => {
+                word_derivations(word, prefix, *typo, &words_fst, &mut cache)?.to_vec()
+            }
+            QueryKind::Exact { word, .. } if prefix => {
+                word_derivations(word, true, 0, &words_fst, &mut cache)?.to_vec()
+            }
+            QueryKind::Exact { word, .. } => vec![(word.clone(), 0)],
+        };
+
+        let typos = derivations.iter().filter(|(_, typo)| *typo > 0).cloned().collect();
+        let prefixes = derivations
+            .into_iter()
+            .filter(|(derived, typo)| *typo == 0 && derived != word)
+            .map(|(derived, _)| derived)
+            .collect();
+
+        let split = tree_phrases.iter().find_map(|phrase| match phrase {
+            [Some(left), Some(right)] if left.len() + right.len() == word.len() => {
+                let (l, r) = word.split_at(left.len());
+                (l == left && r == right).then(|| (left.clone(), right.clone()))
+            }
+            _ => None,
+        });
+
+        Ok(Some(WordExplanation {
+            word: word.to_string(),
+            prefix,
+            synonyms: index.words_synonyms(rtxn, words)?.unwrap_or_default(),
+            typos,
+            prefixes,
+            split,
+        }))
+    };
+
+    let mut explanation = QueryExplanation { exact_only, ..Default::default() };
+    for part in &primitive_query {
+        match part {
+            PrimitiveQueryPart::Word(word, prefix) => {
+                if let Some(word) = explain_word(&[word.as_str()], word, *prefix)? {
+                    explanation.words.push(word);
+                }
+            }
+            PrimitiveQueryPart::Phrase(words) => {
+                explanation.phrases.push(words.iter().flatten().cloned().collect());
+            }
+        }
+    }
+
+    // The ngrams are made of the consecutive words that aren't separated by a phrase.
+    for run in primitive_query.split(|part| matches!(part, PrimitiveQueryPart::Phrase(_))) {
+        for ngram in 2..=3 {
+            for parts in run.windows(ngram) {
+                let words: Vec<_> = parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        PrimitiveQueryPart::Word(word, _) => Some(word.as_str()),
+                        PrimitiveQueryPart::Phrase(_) => None,
+                    })
+                    .collect();
+                let prefix = matches!(parts.last(), Some(PrimitiveQueryPart::Word(_, true)));
+                if let Some(ngram) = explain_word(&words, &words.concat(), prefix)? {
+                    explanation.ngrams.push(ngram);
+                }
+            }
+        }
+    }
+
+    Ok(explanation)
+}
+
+/// Collects the distinct queries and phrases at the leaves of the query tree.
+fn collect_leaves<'o>(
+    operation: &'o Operation,
+    queries: &mut Vec<&'o Query>,
+    phrases: &mut Vec<&'o [Option<String>]>,
+) {
+    match operation {
+        Operation::And(ops) | Operation::Or(_, ops) => {
+            ops.iter().for_each(|op| collect_leaves(op, queries, phrases))
+        }
+        Operation::Query(query) => {
+            if !queries.contains(&query) {
+                queries.push(query);
+            }
+        }
+        Operation::Phrase(words) => {
+            if !phrases.contains(&words.as_slice()) {
+                phrases.push(words);
+            }
+        }
+    }
+}
+
+fn query_word(query: &Query) -> &str {
+    match &query.kind {
+        QueryKind::Tolerant { word, .. } | QueryKind::Exact { word, .. } => word,
+    }
+}
@@ -8,10 +8,11 @@ use roaring::RoaringBitmap;
 
 use super::facet_range_search;
 use crate::error::{Error, UserError};
+use crate::facet::FacetType;
 use crate::heed_codec::facet::{
     FacetGroupKey, FacetGroupKeyCodec, FacetGroupValueCodec, OrderedF64Codec,
 };
-use crate::{distance_between_two_points, lat_lng_to_xyz, FieldId, Index, Result};
+use crate::{distance_between_two_points, lat_lng_to_xyz, FieldId, Index, NullPolicy, Result};
 
 /// The maximum number of filters the filter AST can process.
 const MAX_FILTER_DEPTH: usize = 2000;
@@ -175,12 +176,23 @@ impl<'a> Filter<'a> {
 
 impl<'a> Filter<'a> {
     pub fn evaluate(&self, rtxn: &heed::RoTxn, index: &Index) -> Result<RoaringBitmap> {
+        self.evaluate_with_null_policy(rtxn, index, None)
+    }
+
+    /// Evaluates the filter, the documents without a value for a field are matched
+    /// by the comparisons on this field according to the given [`NullPolicy`].
+    pub fn evaluate_with_null_policy(
+        &self,
+        rtxn: &heed::RoTxn,
+        index: &Index,
+        null_policy: Option<NullPolicy>,
+    ) -> Result<RoaringBitmap> {
         // to avoid doing this for each recursive call we're going to do it ONCE ahead of time
         let soft_deleted_documents = index.soft_deleted_documents_ids(rtxn)?;
         let filterable_fields = index.filterable_fields(rtxn)?;
 
         // and finally we delete all the soft_deleted_documents, again, only once at the very end
-        self.inner_evaluate(rtxn, index, &filterable_fields)
+        self.inner_evaluate(rtxn, index, &filterable_fields, null_policy)
             .map(|result| result - soft_deleted_documents)
     }
 
@@ -189,6 +201,7 @@ impl<'a> Filter<'a> {
         index: &Index,
         field_id: FieldId,
         operator: &Condition<'a>,
+        null_policy: Option<NullPolicy>,
     ) -> Result<RoaringBitmap> {
         let numbers_db = index.facet_id_f64_docids;
         let strings_db = index.facet_id_string_docids;
@@ -239,7 +252,7 @@ impl<'a> Filter<'a> {
             }
             Condition::NotEqual(val) => {
                 let operator = Condition::Equal(val.clone());
-                let docids = Self::evaluate_operator(rtxn, index, field_id, &operator)?;
+                let docids = Self::evaluate_operator(rtxn, index, field_id, &operator, None)?;
                 let all_ids = index.documents_ids(rtxn)?;
                 return Ok(all_ids - docids);
             }
@@ -247,6 +260,23 @@ impl<'a> Filter<'a> {
 
         let mut output = RoaringBitmap::new();
         Self::explore_facet_number_levels(rtxn, numbers_db, field_id, left, right, &mut output)?;
+
+        // The documents without a value are ordered before or after all the values.
+        let nulls_match = match operator {
+            Condition::GreaterThan(_) | Condition::GreaterThanOrEqual(_) => {
+                null_policy == Some(NullPolicy::NullsLast)
+            }
+            Condition::LowerThan(_) | Condition::LowerThanOrEqual(_) => {
+                null_policy == Some(NullPolicy::NullsFirst)
+            }
+            _ => false,
+        };
+        if nulls_match {
+            let number_faceted = index.faceted_documents_ids(rtxn, field_id, FacetType::Number)?;
+            let string_faceted = index.faceted_documents_ids(rtxn, field_id, FacetType::String)?;
+            output |= index.documents_ids(rtxn)? - number_faceted - string_faceted;
+        }
+
         Ok(output)
     }
 
@@ -280,6 +310,7 @@ impl<'a> Filter<'a> {
         rtxn: &heed::RoTxn,
         index: &Index,
         filterable_fields: &HashSet<String>,
+        null_policy: Option<NullPolicy>,
     ) -> Result<RoaringBitmap> {
         match &self.condition {
             FilterCondition::Not(f) => {
@@ -289,6 +320,7 @@ impl<'a> Filter<'a> {
                     rtxn,
                     index,
                     filterable_fields,
+                    null_policy,
                 )?;
                 Ok(all_ids - selected)
             }
@@ -301,7 +333,7 @@ impl<'a> Filter<'a> {
 
                         for el in els {
                             let op = Condition::Equal(el.clone());
-                            let el_bitmap = Self::evaluate_operator(rtxn, index, fid, &op)?;
+                            let el_bitmap = Self::evaluate_operator(rtxn, index, fid, &op, None)?;
                             bitmap |= el_bitmap;
                         }
                         Ok(bitmap)
@@ -319,7 +351,7 @@ impl<'a> Filter<'a> {
                 if crate::is_faceted(fid.value(), filterable_fields) {
                     let field_ids_map = index.fields_ids_map(rtxn)?;
                     if let Some(fid) = field_ids_map.id(fid.value()) {
-                        Self::evaluate_operator(rtxn, index, fid, op)
+                        Self::evaluate_operator(rtxn, index, fid, op, null_policy)
                     } else {
                         Ok(RoaringBitmap::new())
                     }
@@ -333,8 +365,13 @@ impl<'a> Filter<'a> {
             FilterCondition::Or(subfilters) => {
                 let mut bitmap = RoaringBitmap::new();
                 for f in subfilters {
-                    bitmap |=
-                        Self::inner_evaluate(&(f.clone()).into(), rtxn, index, filterable_fields)?;
+                    bitmap |= Self::inner_evaluate(
+                        &(f.clone()).into(),
+                        rtxn,
+                        index,
+                        filterable_fields,
+                        null_policy,
+                    )?;
                 }
                 Ok(bitmap)
             }
@@ -346,6 +383,7 @@ impl<'a> Filter<'a> {
                         rtxn,
                         index,
                         filterable_fields,
+                        null_policy,
                     )?;
                     for f in subfilters_iter {
                         if bitmap.is_empty() {
@@ -356,6 +394,7 @@ impl<'a> Filter<'a> {
                             rtxn,
                             index,
                             filterable_fields,
+                            null_policy,
                         )?;
                     }
                     Ok(bitmap)
@@ -455,6 +494,7 @@ impl<'a> Filter<'a> {
                         rtxn,
                         index,
                         filterable_fields,
+                        null_policy,
                     )?;
 
                     let geo_lng_token = Token::new(
@@ -487,6 +527,7 @@ impl<'a> Filter<'a> {
                             rtxn,
                             index,
                             filterable_fields,
+                            null_policy,
                         )?;
 
                         let condition_right = FilterCondition::Condition {
@@ -500,6 +541,7 @@ impl<'a> Filter<'a> {
                             rtxn,
                             index,
                             filterable_fields,
+                            null_policy,
                         )?;
 
                         left | right
@@ -515,6 +557,7 @@ impl<'a> Filter<'a> {
                             rtxn,
                             index,
                             filterable_fields,
+                            null_policy,
                         )?
                     };
 
@@ -47,6 +47,11 @@ impl MatchingWords {
         Ok(Self { inner: matching_words })
     }
 
+    /// Returns the number of words written by the end user, the words of the phrases included.
+    pub fn query_words_count(&self) -> usize {
+        self.inner.iter().flat_map(|(_, ids)| ids).max().map_or(0, |&id| id as usize + 1)
+    }
+
     /// Returns an iterator over terms that match or partially match the given token.
     pub fn match_token<'a, 'b>(&'a self, token: &'b Token<'b>) -> MatchesIter<'a, 'b> {
         MatchesIter { inner: Box::new(self.inner.iter()), token }
@@ -18,6 +18,7 @@ pub struct MatcherBuilder<'a, A> {
     crop_marker: Option<String>,
     highlight_prefix: Option<String>,
     highlight_suffix: Option<String>,
+    highlight_mode: HighlightMode,
 }
 
 impl<'a, A> MatcherBuilder<'a, A> {
@@ -28,6 +29,7 @@ impl<'a, A> MatcherBuilder<'a, A> {
             crop_marker: None,
             highlight_prefix: None,
             highlight_suffix: None,
+            highlight_mode: HighlightMode::default(),
         }
     }
 
@@ -46,6 +48,11 @@ impl<'a, A> MatcherBuilder<'a, A> {
         self
     }
 
+    pub fn highlight_mode(&mut self, mode: HighlightMode) -> &Self {
+        self.highlight_mode = mode;
+        self
+    }
+
     pub fn build<'t, 'm>(&'m self, text: &'t str) -> Matcher<'t, 'm, A> {
         let crop_marker = match &self.crop_marker {
             Some(marker) => marker.as_str(),
@@ -67,11 +74,28 @@ impl<'a, A> MatcherBuilder<'a, A> {
             crop_marker,
             highlight_prefix,
             highlight_suffix,
+            highlight_mode: self.highlight_mode,
             matches: None,
         }
     }
 }
 
+/// Defines which part of a matching word is wrapped by the highlight tags.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HighlightMode {
+    /// Only highlight the part of the word matching the query,
+    /// e.g. `prog` in `programming` for a prefix match.
+    MatchedSpan,
+    /// Always highlight the whole matching word.
+    WholeWord,
+}
+
+impl Default for HighlightMode {
+    fn default() -> Self {
+        Self::MatchedSpan
+    }
+}
+
 #[derive(Copy, Clone, Default)]
 pub struct FormatOptions {
     pub highlight: bool,
@@ -110,6 +134,7 @@ pub struct Matcher<'t, 'm, A> {
     crop_marker: &'m str,
     highlight_prefix: &'m str,
     highlight_suffix: &'m str,
+    highlight_mode: HighlightMode,
     matches: Option<(Vec<Token<'t>>, Vec<Match>)>,
 }
 
@@ -229,14 +254,37 @@ impl<'t, A: AsRef<[u8]>> Matcher<'t, '_, A> {
             None => self.compute_matches().matches(),
             Some((tokens, matches)) => matches
                 .iter()
-                .map(|m| MatchBounds {
-                    start: tokens[m.token_position].byte_start,
-                    length: m.match_len,
+                .map(|m| {
+                    let token = &tokens[m.token_position];
+                    let length = match self.highlight_mode {
+                        // the whole word is highlighted, not only the matched prefix.
+                        HighlightMode::WholeWord => {
+                            self.text[token.byte_start..token.byte_end].chars().count()
+                        }
+                        HighlightMode::MatchedSpan => m.match_len,
+                    };
+                    MatchBounds { start: token.byte_start, length }
                 })
                 .collect(),
         }
     }
 
+    /// Returns the ids of the query words that match the text, sorted and deduplicated.
+    ///
+    /// The ids are the positions of the words in the query, a single matching
+    /// text word can match several query words that are concatenated.
+    pub fn matched_query_words(&mut self) -> Vec<PrimitiveWordId> {
+        match &self.matches {
+            None => self.compute_matches().matched_query_words(),
+            Some((_, matches)) => {
+                let mut ids: Vec<_> = matches.iter().flat_map(|m| m.ids.iter().copied()).collect();
+                ids.sort_unstable();
+                ids.dedup();
+                ids
+            }
+        }
+    }
+
     /// Returns the bounds in byte index of the crop window.
     fn crop_bounds(&self, tokens: &[Token], matches: &[Match], crop_size: usize) -> (usize, usize) {
         // if there is no match, we start from the beginning of the string by default.
@@ -452,11 +500,14 @@ impl<'t, A: AsRef<[u8]>> Matcher<'t, '_, A> {
                                 formatted.push(&self.text[byte_index..token.byte_start]);
                             }
 
-                            let highlight_byte_index = self.text[token.byte_start..]
-                                .char_indices()
-                                .enumerate()
-                                .find(|(i, _)| *i == m.match_len)
-                                .map_or(token.byte_end, |(_, (i, _))| i + token.byte_start);
+                            let highlight_byte_index = match self.highlight_mode {
+                                HighlightMode::WholeWord => token.byte_end,
+                                HighlightMode::MatchedSpan => self.text[token.byte_start..]
+                                    .char_indices()
+                                    .enumerate()
+                                    .find(|(i, _)| *i == m.match_len)
+                                    .map_or(token.byte_end, |(_, (i, _))| i + token.byte_start),
+                            };
                             formatted.push(self.highlight_prefix);
                             formatted.push(&self.text[token.byte_start..highlight_byte_index]);
                             formatted.push(self.highlight_suffix);
@@ -549,6 +600,29 @@ mod tests {
         assert_eq!(&matcher.format(format_options), &text);
     }
 
+    #[test]
+    fn query_words_count() {
+        assert_eq!(matching_words().query_words_count(), 3);
+        assert_eq!(MatchingWords::default().query_words_count(), 0);
+    }
+
+    #[test]
+    fn matched_query_words() {
+        let matching_words = matching_words();
+
+        let builder = MatcherBuilder::from_matching_words(matching_words);
+
+        // Text without any match.
+        let text = "A quick brown fox can not jump 32 feet, right? Brr, it is cold!";
+        let mut matcher = builder.build(text);
+        assert!(matcher.matched_query_words().is_empty());
+
+        // Text containing two of the three query words, one of them twice.
+        let text = "Natalie risk her future to build a world with the boy she loves, the end.";
+        let mut matcher = builder.build(text);
+        assert_eq!(matcher.matched_query_words(), vec![1, 2]);
+    }
+
     #[test]
     fn format_highlight() {
         let matching_words = matching_words();
@@ -592,6 +666,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_highlight_mode() {
+        let matching_words =
+            vec![(vec![Rc::new(MatchingWord::new("prog".to_string(), 0, true).unwrap())], vec![0])];
+        let matching_words = MatchingWords::new(matching_words).unwrap();
+
+        let mut builder = MatcherBuilder::from_matching_words(matching_words);
+        let format_options = FormatOptions { highlight: true, crop: None };
+        let text = "I love programming.";
+
+        // by default only the matched prefix is highlighted.
+        let mut matcher = builder.build(text);
+        insta::assert_snapshot!(
+            matcher.format(format_options),
+            @"I love <em>prog</em>ramming."
+        );
+
+        let mut matcher = builder.build(text);
+        assert_eq!(matcher.matches(), vec![MatchBounds { start: 7, length: 4 }]);
+
+        builder.highlight_mode(HighlightMode::WholeWord);
+        let mut matcher = builder.build(text);
+        insta::assert_snapshot!(
+            matcher.format(format_options),
+            @"I love <em>programming</em>."
+        );
+        let mut matcher = builder.build(text);
+        assert_eq!(matcher.matches(), vec![MatchBounds { start: 7, length: 11 }]);
+    }
+
     #[test]
     fn highlight_unicode() {
         let all = vec![
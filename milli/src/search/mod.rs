@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::collections::hash_map::{Entry, HashMap};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::mem::take;
 use std::result::Result as StdResult;
@@ -7,39 +8,59 @@ use std::str::Utf8Error;
 use std::time::Instant;
 
 use charabia::TokenizerBuilder;
-use distinct::{Distinct, DocIter, FacetDistinct, NoopDistinct};
+use distinct::{ContentDistinct, Distinct, DocIter, FacetDistinct, NoopDistinct};
 use fst::automaton::Str;
 use fst::{Automaton, IntoStreamer, Streamer};
 use levenshtein_automata::{LevenshteinAutomatonBuilder as LevBuilder, DFA};
 use log::debug;
 use once_cell::sync::Lazy;
+use ordered_float::OrderedFloat;
 use roaring::bitmap::RoaringBitmap;
 
+pub(crate) use self::explain::explain_query;
+pub use self::explain::{QueryExplanation, WordExplanation};
 pub use self::facet::{FacetDistribution, Filter, DEFAULT_VALUES_PER_FACET};
 use self::fst_utils::{Complement, Intersection, StartsWith, Union};
 pub use self::matches::{
-    FormatOptions, MatchBounds, Matcher, MatcherBuilder, MatchingWord, MatchingWords,
+    FormatOptions, HighlightMode, MatchBounds, Matcher, MatcherBuilder, MatchingWord, MatchingWords,
 };
-use self::query_tree::QueryTreeBuilder;
+pub(crate) use self::query_ast::search_ast;
+pub use self::query_ast::{QueryAst, SearchOptions};
+pub use self::query_tree::PrimitiveQueryPart;
+use self::query_tree::{
+    create_primitive_query, Operation, PrimitiveQuery, Query, QueryKind, QueryTreeBuilder,
+};
+pub(crate) use self::suggest::suggest_correction;
 use crate::error::UserError;
+use crate::facet::FacetType;
 use crate::search::criteria::r#final::{Final, FinalResult};
-use crate::search::criteria::InitialCandidates;
-use crate::{AscDesc, Criterion, DocumentId, Index, Member, Result};
+use crate::search::criteria::{resolve_query_tree, Context, CriteriaBuilder, InitialCandidates};
+use crate::{
+    AscDesc, Criterion, DocumentId, FieldId, Index, Member, Result, RESERVED_BOOST_FIELD_NAME,
+};
 
 // Building these factories is not free.
 static LEVDIST0: Lazy<LevBuilder> = Lazy::new(|| LevBuilder::new(0, true));
 static LEVDIST1: Lazy<LevBuilder> = Lazy::new(|| LevBuilder::new(1, true));
 static LEVDIST2: Lazy<LevBuilder> = Lazy::new(|| LevBuilder::new(2, true));
 
+/// The number of query words searched when [`Search::words_limit`] isn't called,
+/// the next words are ignored.
+pub(crate) const DEFAULT_WORDS_LIMIT: usize = 10;
+
 mod criteria;
 mod distinct;
+mod explain;
 pub mod facet;
 mod fst_utils;
 mod matches;
+mod query_ast;
 mod query_tree;
+mod suggest;
 
 pub struct Search<'a> {
     query: Option<String>,
+    query_terms: Option<PrimitiveQuery>,
     // this should be linked to the String in the query
     filter: Option<Filter<'a>>,
     offset: usize,
@@ -49,6 +70,9 @@ pub struct Search<'a> {
     authorize_typos: bool,
     words_limit: usize,
     exhaustive_number_hits: bool,
+    dedup_by_content: bool,
+    attribute_order: Option<Vec<String>>,
+    null_policy: Option<NullPolicy>,
     criterion_implementation_strategy: CriterionImplementationStrategy,
     rtxn: &'a heed::RoTxn<'a>,
     index: &'a Index,
@@ -58,6 +82,7 @@ impl<'a> Search<'a> {
     pub fn new(rtxn: &'a heed::RoTxn, index: &'a Index) -> Search<'a> {
         Search {
             query: None,
+            query_terms: None,
             filter: None,
             offset: 0,
             limit: 20,
@@ -65,7 +90,10 @@ impl<'a> Search<'a> {
             terms_matching_strategy: TermsMatchingStrategy::default(),
             authorize_typos: true,
             exhaustive_number_hits: false,
-            words_limit: 10,
+            dedup_by_content: false,
+            attribute_order: None,
+            null_policy: None,
+            words_limit: DEFAULT_WORDS_LIMIT,
             criterion_implementation_strategy: CriterionImplementationStrategy::default(),
             rtxn,
             index,
@@ -77,6 +105,12 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Searches the given words and phrases instead of parsing the query string.
+    pub fn query_terms(&mut self, terms: Vec<PrimitiveQueryPart>) -> &mut Search<'a> {
+        self.query_terms = Some(terms);
+        self
+    }
+
     pub fn offset(&mut self, offset: usize) -> &mut Search<'a> {
         self.offset = offset;
         self
@@ -119,6 +153,28 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Only keep the first of the documents having the same displayed content, the primary key
+    /// excepted. The returned documents are read to find them, this slows the search.
+    pub fn dedup_by_content(&mut self, dedup_by_content: bool) -> &mut Search<'a> {
+        self.dedup_by_content = dedup_by_content;
+        self
+    }
+
+    /// Overrides the order of the attributes used by the attribute ranking rule for this
+    /// search. The unknown attributes are ignored and the attributes that are not listed
+    /// keep their order after the listed ones.
+    pub fn attribute_order(&mut self, attributes: Vec<String>) -> &mut Search<'a> {
+        self.attribute_order = Some(attributes);
+        self
+    }
+
+    /// Defines how the documents without a value for a field are handled by the sort
+    /// criteria and the comparison filters of this search.
+    pub fn null_policy(&mut self, null_policy: NullPolicy) -> &mut Search<'a> {
+        self.null_policy = Some(null_policy);
+        self
+    }
+
     pub fn criterion_implementation_strategy(
         &mut self,
         strategy: CriterionImplementationStrategy,
@@ -136,31 +192,23 @@ impl<'a> Search<'a> {
     pub fn execute(&self) -> Result<SearchResult> {
         // We create the query tree by spliting the query into tokens.
         let before = Instant::now();
-        let (query_tree, primitive_query, matching_words) = match self.query.as_ref() {
-            Some(query) => {
+        let primitive_query = match (&self.query_terms, &self.query) {
+            (Some(terms), _) => Some(terms.clone()),
+            (None, Some(query)) => {
+                Some(parse_query(self.rtxn, self.index, query, Some(self.words_limit))?)
+            }
+            (None, None) => None,
+        };
+        let (query_tree, primitive_query, matching_words) = match primitive_query {
+            Some(primitive_query) => {
                 let mut builder = QueryTreeBuilder::new(self.rtxn, self.index)?;
                 builder.terms_matching_strategy(self.terms_matching_strategy);
 
                 builder.authorize_typos(self.is_typo_authorized()?);
 
                 builder.words_limit(self.words_limit);
-                // We make sure that the analyzer is aware of the stop words
-                // this ensures that the query builder is able to properly remove them.
-                let mut tokbuilder = TokenizerBuilder::new();
-                let stop_words = self.index.stop_words(self.rtxn)?;
-                if let Some(ref stop_words) = stop_words {
-                    tokbuilder.stop_words(stop_words);
-                }
-
-                let script_lang_map = self.index.script_language(self.rtxn)?;
-                if !script_lang_map.is_empty() {
-                    tokbuilder.allow_list(&script_lang_map);
-                }
-
-                let tokenizer = tokbuilder.build();
-                let tokens = tokenizer.tokenize(query);
                 builder
-                    .build(tokens)?
+                    .build_from_primitive_query(primitive_query)?
                     .map_or((None, None, None), |(qt, pq, mw)| (Some(qt), Some(pq), Some(mw)))
             }
             None => (None, None, None),
@@ -171,7 +219,11 @@ impl<'a> Search<'a> {
         // We create the original candidates with the facet conditions results.
         let before = Instant::now();
         let filtered_candidates = match &self.filter {
-            Some(condition) => Some(condition.evaluate(self.rtxn, self.index)?),
+            Some(condition) => Some(condition.evaluate_with_null_policy(
+                self.rtxn,
+                self.index,
+                self.null_policy,
+            )?),
             None => None,
         };
 
@@ -200,6 +252,37 @@ impl<'a> Search<'a> {
             }
         }
 
+        // The documents without a value for one of the sorted fields are removed from the candidates.
+        let filtered_candidates = match (&self.sort_criteria, self.null_policy) {
+            (Some(sort_criteria), Some(NullPolicy::ExcludeNulls)) => {
+                let fields_ids_map = self.index.fields_ids_map(self.rtxn)?;
+                let mut candidates = match filtered_candidates {
+                    Some(candidates) => candidates,
+                    None => self.index.documents_ids(self.rtxn)?,
+                };
+                for asc_desc in sort_criteria {
+                    if let Member::Field(field) = asc_desc.member() {
+                        candidates &= match fields_ids_map.id(field) {
+                            Some(fid) => {
+                                self.index.faceted_documents_ids(
+                                    self.rtxn,
+                                    fid,
+                                    FacetType::Number,
+                                )? | self.index.faceted_documents_ids(
+                                    self.rtxn,
+                                    fid,
+                                    FacetType::String,
+                                )?
+                            }
+                            None => RoaringBitmap::new(),
+                        };
+                    }
+                }
+                Some(candidates)
+            }
+            (_, _) => filtered_candidates,
+        };
+
         // We check that the sort ranking rule exists and throw an
         // error if we try to use it and that it doesn't.
         let sort_ranking_rule_missing = !self.index.criteria(self.rtxn)?.contains(&Criterion::Sort);
@@ -208,10 +291,44 @@ impl<'a> Search<'a> {
             return Err(UserError::SortRankingRuleMissing.into());
         }
 
-        let criteria_builder = criteria::CriteriaBuilder::new(self.rtxn, self.index)?;
+        let mut criteria_builder = CriteriaBuilder::new(self.rtxn, self.index)?;
+        if let Some(attribute_order) = &self.attribute_order {
+            criteria_builder.attribute_order(attribute_order)?;
+        }
+        if self.null_policy == Some(NullPolicy::NullsFirst) {
+            criteria_builder.sort_nulls_first(true);
+        }
+
+        // The typo and prefix derivations are skipped when the exact query matches enough documents.
+        let query_tree = match (query_tree, self.index.min_exact_results(self.rtxn)?) {
+            (Some(query_tree), Some(min_exact_results)) => {
+                let exact_query_tree = exact_query_tree(&query_tree);
+                if has_enough_exact_results(
+                    &criteria_builder,
+                    &exact_query_tree,
+                    filtered_candidates.as_ref(),
+                    min_exact_results,
+                )? {
+                    debug!("exact query tree: {:?}", exact_query_tree);
+                    Some(exact_query_tree)
+                } else {
+                    Some(query_tree)
+                }
+            }
+            (query_tree, _) => query_tree,
+        };
+        let matching_words = matching_words.unwrap_or_default();
+
+        let facet_distinct = match self.index.distinct_field(self.rtxn)? {
+            Some(name) => match self.index.fields_ids_map(self.rtxn)?.id(name) {
+                Some(fid) => Some(FacetDistinct::new(fid, self.index, self.rtxn)),
+                None => return Ok(SearchResult::default()),
+            },
+            None => None,
+        };
 
-        match self.index.distinct_field(self.rtxn)? {
-            None => {
+        match (facet_distinct, self.dedup_by_content) {
+            (None, false) => {
                 let criteria = criteria_builder.build::<NoopDistinct>(
                     query_tree,
                     primitive_query,
@@ -221,31 +338,64 @@ impl<'a> Search<'a> {
                     None,
                     self.criterion_implementation_strategy,
                 )?;
-                self.perform_sort(NoopDistinct, matching_words.unwrap_or_default(), criteria)
+                self.perform_sort(NoopDistinct, matching_words, criteria)
             }
-            Some(name) => {
-                let field_ids_map = self.index.fields_ids_map(self.rtxn)?;
-                match field_ids_map.id(name) {
-                    Some(fid) => {
-                        let distinct = FacetDistinct::new(fid, self.index, self.rtxn);
-
-                        let criteria = criteria_builder.build(
-                            query_tree,
-                            primitive_query,
-                            filtered_candidates,
-                            self.sort_criteria.clone(),
-                            self.exhaustive_number_hits,
-                            Some(distinct.clone()),
-                            self.criterion_implementation_strategy,
-                        )?;
-                        self.perform_sort(distinct, matching_words.unwrap_or_default(), criteria)
-                    }
-                    None => Ok(SearchResult::default()),
-                }
+            (None, true) => {
+                let distinct = ContentDistinct::new(NoopDistinct, self.index, self.rtxn)?;
+                self.perform_distinct_sort(
+                    &criteria_builder,
+                    distinct,
+                    query_tree,
+                    primitive_query,
+                    filtered_candidates,
+                    matching_words,
+                )
+            }
+            (Some(distinct), false) => self.perform_distinct_sort(
+                &criteria_builder,
+                distinct,
+                query_tree,
+                primitive_query,
+                filtered_candidates,
+                matching_words,
+            ),
+            (Some(distinct), true) => {
+                let distinct = ContentDistinct::new(distinct, self.index, self.rtxn)?;
+                self.perform_distinct_sort(
+                    &criteria_builder,
+                    distinct,
+                    query_tree,
+                    primitive_query,
+                    filtered_candidates,
+                    matching_words,
+                )
             }
         }
     }
 
+    /// Builds the criteria with the distinct, in order to also apply it
+    /// to the initial candidates, and performs the sort.
+    fn perform_distinct_sort<'t, D: 't + Distinct + Clone>(
+        &self,
+        criteria_builder: &'t CriteriaBuilder<'t>,
+        distinct: D,
+        query_tree: Option<Operation>,
+        primitive_query: Option<PrimitiveQuery>,
+        filtered_candidates: Option<RoaringBitmap>,
+        matching_words: MatchingWords,
+    ) -> Result<SearchResult> {
+        let criteria = criteria_builder.build(
+            query_tree,
+            primitive_query,
+            filtered_candidates,
+            self.sort_criteria.clone(),
+            self.exhaustive_number_hits,
+            Some(distinct.clone()),
+            self.criterion_implementation_strategy,
+        )?;
+        self.perform_sort(distinct, matching_words, criteria)
+    }
+
     fn perform_sort<D: Distinct>(
         &self,
         mut distinct: D,
@@ -256,27 +406,35 @@ impl<'a> Search<'a> {
         let mut initial_candidates = InitialCandidates::Estimated(RoaringBitmap::new());
         let mut excluded_candidates = self.index.soft_deleted_documents_ids(self.rtxn)?;
         let mut documents_ids = Vec::new();
+        let boost_field_id = self.index.fields_ids_map(self.rtxn)?.id(RESERVED_BOOST_FIELD_NAME);
 
         while let Some(FinalResult { candidates, initial_candidates: ic, .. }) =
             criteria.next(&excluded_candidates)?
         {
             debug!("Number of candidates found {}", candidates.len());
 
-            let excluded = take(&mut excluded_candidates);
-            let mut candidates = distinct.distinct(candidates, excluded);
-
             initial_candidates |= ic;
 
-            if offset != 0 {
-                let discarded = candidates.by_ref().take(offset).count();
-                offset = offset.saturating_sub(discarded);
-            }
+            // The documents the ranking rules consider equally relevant are ordered by their boost.
+            for candidates in self.boost_buckets(boost_field_id, candidates)? {
+                let excluded = take(&mut excluded_candidates);
+                let mut candidates = distinct.distinct(candidates, excluded);
 
-            for candidate in candidates.by_ref().take(self.limit - documents_ids.len()) {
-                documents_ids.push(candidate?);
-            }
+                if offset != 0 {
+                    let discarded = candidates.by_ref().take(offset).count();
+                    offset = offset.saturating_sub(discarded);
+                }
+
+                for candidate in candidates.by_ref().take(self.limit - documents_ids.len()) {
+                    documents_ids.push(candidate?);
+                }
 
-            excluded_candidates |= candidates.into_excluded();
+                excluded_candidates |= candidates.into_excluded();
+
+                if documents_ids.len() == self.limit {
+                    break;
+                }
+            }
 
             if documents_ids.len() == self.limit {
                 break;
@@ -291,12 +449,47 @@ impl<'a> Search<'a> {
             documents_ids,
         })
     }
+
+    /// Splits a bucket of equally relevant documents by boost, the most boosted documents first.
+    ///
+    /// The documents without a `_boost` field have a boost of 1.0.
+    fn boost_buckets(
+        &self,
+        boost_field_id: Option<FieldId>,
+        candidates: RoaringBitmap,
+    ) -> Result<Vec<RoaringBitmap>> {
+        let boost_field_id = match boost_field_id {
+            Some(field_id) => field_id,
+            None => return Ok(vec![candidates]),
+        };
+        let boosted =
+            self.index.faceted_documents_ids(self.rtxn, boost_field_id, FacetType::Number)?
+                & &candidates;
+        if boosted.is_empty() {
+            return Ok(vec![candidates]);
+        }
+
+        let mut buckets: BTreeMap<OrderedFloat<f64>, RoaringBitmap> = BTreeMap::new();
+        *buckets.entry(OrderedFloat(1.0)).or_default() = &candidates - &boosted;
+        for docid in boosted {
+            let left = (boost_field_id, docid, f64::MIN);
+            let right = (boost_field_id, docid, f64::MAX);
+            let mut iter =
+                self.index.field_id_docid_facet_f64s.range(self.rtxn, &(left..=right))?;
+            if let Some(((_, _, boost), ())) = iter.next().transpose()? {
+                buckets.entry(OrderedFloat(boost)).or_default().insert(docid);
+            }
+        }
+
+        Ok(buckets.into_values().rev().filter(|bucket| !bucket.is_empty()).collect())
+    }
 }
 
 impl fmt::Debug for Search<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let Search {
             query,
+            query_terms,
             filter,
             offset,
             limit,
@@ -305,12 +498,16 @@ impl fmt::Debug for Search<'_> {
             authorize_typos,
             words_limit,
             exhaustive_number_hits,
+            dedup_by_content,
+            attribute_order,
+            null_policy,
             criterion_implementation_strategy,
             rtxn: _,
             index: _,
         } = self;
         f.debug_struct("Search")
             .field("query", query)
+            .field("query_terms", query_terms)
             .field("filter", filter)
             .field("offset", offset)
             .field("limit", limit)
@@ -318,6 +515,9 @@ impl fmt::Debug for Search<'_> {
             .field("terms_matching_strategy", terms_matching_strategy)
             .field("authorize_typos", authorize_typos)
             .field("exhaustive_number_hits", exhaustive_number_hits)
+            .field("dedup_by_content", dedup_by_content)
+            .field("attribute_order", attribute_order)
+            .field("null_policy", null_policy)
             .field("criterion_implementation_strategy", criterion_implementation_strategy)
             .field("words_limit", words_limit)
             .finish()
@@ -362,6 +562,49 @@ impl Default for TermsMatchingStrategy {
     }
 }
 
+/// How the documents without a value for a field are handled by the sort
+/// criteria and the comparison filters on this field.
+///
+/// Without a policy, these documents are sorted after all the others
+/// and never match a comparison filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullPolicy {
+    /// The missing values are smaller than any value: the documents are sorted first
+    /// and match the `<` and `<=` filters.
+    NullsFirst,
+    /// The missing values are greater than any value: the documents are sorted last
+    /// and match the `>` and `>=` filters.
+    NullsLast,
+    /// The documents are removed from the sorted results and never match a comparison filter.
+    ExcludeNulls,
+}
+
+/// Tokenizes and normalizes the query string into its words and phrases,
+/// the stop words of the index are removed.
+pub(crate) fn parse_query(
+    rtxn: &heed::RoTxn,
+    index: &Index,
+    query: &str,
+    words_limit: Option<usize>,
+) -> Result<PrimitiveQuery> {
+    // We make sure that the analyzer is aware of the stop words
+    // this ensures that the query builder is able to properly remove them.
+    let mut tokbuilder = TokenizerBuilder::new();
+    let stop_words = index.stop_words(rtxn)?;
+    if let Some(ref stop_words) = stop_words {
+        tokbuilder.stop_words(stop_words);
+    }
+
+    let script_lang_map = index.script_language(rtxn)?;
+    if !script_lang_map.is_empty() {
+        tokbuilder.allow_list(&script_lang_map);
+    }
+
+    let tokenizer = tokbuilder.build();
+    let tokens = tokenizer.tokenize(query);
+    Ok(create_primitive_query(tokens, words_limit, index.max_token_length(rtxn)?))
+}
+
 pub type WordDerivationsCache = HashMap<(String, bool, u8), Vec<(String, u8)>>;
 
 pub fn word_derivations<'c>(
@@ -425,6 +668,37 @@ pub fn word_derivations<'c>(
     }
 }
 
+/// Returns the query tree without the typo and prefix derivations of its words.
+pub(crate) fn exact_query_tree(operation: &Operation) -> Operation {
+    match operation {
+        Operation::And(ops) => Operation::And(ops.iter().map(exact_query_tree).collect()),
+        Operation::Or(optional, ops) => {
+            Operation::Or(*optional, ops.iter().map(exact_query_tree).collect())
+        }
+        Operation::Phrase(words) => Operation::Phrase(words.clone()),
+        Operation::Query(Query { kind, .. }) => Operation::Query(Query {
+            prefix: false,
+            kind: QueryKind::exact(kind.word().to_string()),
+        }),
+    }
+}
+
+/// Returns `true` if the exact query tree matches at least `min_exact_results` documents.
+pub(crate) fn has_enough_exact_results(
+    ctx: &dyn Context,
+    exact_query_tree: &Operation,
+    filtered_candidates: Option<&RoaringBitmap>,
+    min_exact_results: usize,
+) -> Result<bool> {
+    let mut candidates =
+        resolve_query_tree(ctx, exact_query_tree, &mut WordDerivationsCache::new())?;
+    candidates &= ctx.documents_ids()?;
+    if let Some(filtered_candidates) = filtered_candidates {
+        candidates &= filtered_candidates;
+    }
+    Ok(candidates.len() >= min_exact_results as u64)
+}
+
 fn get_first(s: &str) -> &str {
     match s.chars().next() {
         Some(c) => &s[..c.len_utf8()],
@@ -448,6 +722,9 @@ pub fn build_dfa(word: &str, typos: u8, is_prefix: bool) -> DFA {
 
 #[cfg(test)]
 mod test {
+    use big_s::S;
+    use maplit::{btreemap, hashset};
+
     use super::*;
     use crate::index::tests::TempIndex;
 
@@ -498,6 +775,291 @@ mod test {
         assert!(!search.is_typo_authorized().unwrap());
     }
 
+    #[test]
+    fn test_dedup_by_content() {
+        let index = TempIndex::new();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "the quick brown fox" },
+                { "id": 1, "title": "the quick brown fox" },
+                { "id": 2, "title": "the quick red fox" },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+        let mut search = Search::new(&txn, &index);
+        search.query("fox");
+
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![0, 1, 2]);
+
+        search.dedup_by_content(true);
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![0, 2]);
+
+        // the duplicates of the skipped documents are not returned either.
+        search.offset(1);
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![2]);
+    }
+
+    #[test]
+    fn test_prefix_only_matches_word_starts() {
+        let index = TempIndex::new();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "a category" },
+                { "id": 1, "title": "scatter" },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+        let mut search = Search::new(&txn, &index);
+        search.query("cat");
+
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![0]);
+    }
+
+    #[test]
+    fn test_non_prefix_attributes() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_non_prefix_attributes(hashset! { S("tag") });
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "a category", "tag": "dog" },
+                { "id": 1, "title": "scatter", "tag": "category" },
+                { "id": 2, "title": "scatter", "tag": "cat" },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+
+        // the prefix is only completed in the title.
+        let mut search = Search::new(&txn, &index);
+        search.query("cat");
+        let SearchResult { mut documents_ids, .. } = search.execute().unwrap();
+        documents_ids.sort_unstable();
+        assert_eq!(documents_ids, vec![0, 2]);
+
+        // but the whole words still match in the tag.
+        let mut search = Search::new(&txn, &index);
+        search.query("category");
+        let SearchResult { mut documents_ids, .. } = search.execute().unwrap();
+        documents_ids.sort_unstable();
+        assert_eq!(documents_ids, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_attributes_coverage() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_criteria(vec![Criterion::Attribute]);
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "fox", "body": "quick" },
+                { "id": 1, "title": "dog", "body": "quick fox" },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+        let mut search = Search::new(&txn, &index);
+        search.query("quick fox");
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![0, 1]);
+        drop(txn);
+
+        // a single matching word is no longer enough to rank the title first.
+        index
+            .update_settings(|settings| {
+                settings.set_attributes_coverage(btreemap! { S("title") => 2 });
+            })
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+        let mut search = Search::new(&txn, &index);
+        search.query("quick fox");
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![1, 0]);
+    }
+
+    #[test]
+    fn test_attribute_order() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_criteria(vec![Criterion::Attribute]);
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "phone case", "brand": "acme" },
+                { "id": 1, "title": "acme phone", "brand": "nokia" },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+
+        // the title is defined first.
+        let mut search = Search::new(&txn, &index);
+        search.query("acme");
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![1, 0]);
+
+        // the unknown attributes are ignored.
+        let mut search = Search::new(&txn, &index);
+        search.query("acme");
+        search.attribute_order(vec![S("unknown"), S("brand")]);
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_null_policy() {
+        use std::str::FromStr;
+
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_sortable_fields(hashset! { S("price") });
+                settings.set_filterable_fields(hashset! { S("price") });
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "price": 10 },
+                { "id": 1, "name": "no price" },
+                { "id": 2, "price": 5 },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+        let search_sorted = |sort: &str, null_policy: NullPolicy| {
+            let mut search = Search::new(&txn, &index);
+            search.sort_criteria(vec![AscDesc::from_str(sort).unwrap()]);
+            search.null_policy(null_policy);
+            search.execute().unwrap().documents_ids
+        };
+
+        // the document without a price is at the end in both directions.
+        assert_eq!(search_sorted("price:asc", NullPolicy::NullsLast), vec![2, 0, 1]);
+        assert_eq!(search_sorted("price:desc", NullPolicy::NullsLast), vec![0, 2, 1]);
+        assert_eq!(search_sorted("price:asc", NullPolicy::NullsFirst), vec![1, 2, 0]);
+        assert_eq!(search_sorted("price:desc", NullPolicy::NullsFirst), vec![1, 0, 2]);
+        assert_eq!(search_sorted("price:asc", NullPolicy::ExcludeNulls), vec![2, 0]);
+
+        let search_filtered = |filter: &str, null_policy: Option<NullPolicy>| {
+            let mut search = Search::new(&txn, &index);
+            search.filter(Filter::from_str(filter).unwrap().unwrap());
+            if let Some(null_policy) = null_policy {
+                search.null_policy(null_policy);
+            }
+            search.execute().unwrap().documents_ids
+        };
+
+        assert_eq!(search_filtered("price > 1", None), vec![0, 2]);
+        assert_eq!(search_filtered("price > 1", Some(NullPolicy::ExcludeNulls)), vec![0, 2]);
+        assert_eq!(search_filtered("price 1 TO 20", Some(NullPolicy::ExcludeNulls)), vec![0, 2]);
+        assert_eq!(search_filtered("price > 1", Some(NullPolicy::NullsLast)), vec![0, 1, 2]);
+        assert_eq!(search_filtered("price < 20", Some(NullPolicy::NullsLast)), vec![0, 2]);
+        assert_eq!(search_filtered("price < 20", Some(NullPolicy::NullsFirst)), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_document_boost() {
+        use crate::{Error, UserError};
+
+        let index = TempIndex::new();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "name": "doggo", "_boost": 0.5 },
+                { "id": 1, "name": "doggo" },
+                { "id": 2, "name": "doggo", "_boost": 2.0 },
+                { "id": 3, "name": "catto", "_boost": 10.0 },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+        let mut search = Search::new(&txn, &index);
+        search.query("doggo");
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![2, 1, 0]);
+
+        // the pagination follows the boosted order.
+        search.offset(1).limit(1);
+        let SearchResult { documents_ids, .. } = search.execute().unwrap();
+        assert_eq!(documents_ids, vec![1]);
+        drop(txn);
+
+        let err = index.add_documents(documents!({ "id": 4, "_boost": "high" })).unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::InvalidDocumentBoost { .. })));
+        let err = index.add_documents(documents!({ "id": 4, "_boost": -1 })).unwrap_err();
+        assert!(matches!(err, Error::UserError(UserError::InvalidDocumentBoost { .. })));
+    }
+
+    #[test]
+    fn test_search_ast() {
+        use crate::{Condition, FilterCondition, Token};
+
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("price") });
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "the quick brown fox", "price": 5 },
+                { "id": 1, "title": "quick fox", "price": 15 },
+                { "id": 2, "title": "the slow fox", "price": 20 },
+                { "id": 3, "title": "tortoise", "price": 30 },
+                { "id": 4, "title": "a quick tortoise", "price": 40 },
+            ]))
+            .unwrap();
+
+        let txn = index.read_txn().unwrap();
+
+        let query = QueryAst {
+            terms: vec![
+                PrimitiveQueryPart::Word(S("quick"), false),
+                PrimitiveQueryPart::Word(S("fox"), true),
+            ],
+            filter: Some(Filter::from(FilterCondition::Condition {
+                fid: Token::from("price"),
+                op: Condition::GreaterThan(Token::from("10")),
+            })),
+        };
+        let ast_result = index.search_ast(&txn, &query, &SearchOptions::default()).unwrap();
+
+        let mut search = Search::new(&txn, &index);
+        search.query("quick fox");
+        search.filter(Filter::from_str("price > 10").unwrap().unwrap());
+        let string_result = search.execute().unwrap();
+
+        assert_eq!(ast_result.documents_ids, string_result.documents_ids);
+        assert_eq!(ast_result.candidates, string_result.candidates);
+        assert_eq!(ast_result.documents_ids, vec![1, 4]);
+
+        // the string query is parsed into the same terms.
+        let parsed = QueryAst::parse(&index, &txn, "quick fox").unwrap();
+        assert_eq!(parsed.terms, query.terms);
+    }
+
     #[test]
     fn test_one_typos_tolerance() {
         let fst = fst::Set::from_iter(["zealand"].iter()).unwrap().map_data(Cow::Owned).unwrap();
@@ -0,0 +1,73 @@
+use super::{parse_query, Filter, PrimitiveQueryPart, Search, SearchResult, TermsMatchingStrategy};
+use crate::{Index, Result};
+
+/// A query given by its structure rather than by a string to parse.
+///
+/// The words of the terms are searched as they are given, they must be normalized
+/// like the tokenizer does, [`QueryAst::parse`] builds the terms of a query string.
+#[derive(Debug, Clone, Default)]
+pub struct QueryAst<'a> {
+    /// The words and phrases of the query, in order.
+    pub terms: Vec<PrimitiveQueryPart>,
+    /// The filter the documents must match.
+    pub filter: Option<Filter<'a>>,
+}
+
+impl QueryAst<'_> {
+    /// Tokenizes and normalizes the query string with the settings of the index,
+    /// the returned query doesn't have any filter.
+    pub fn parse(index: &Index, rtxn: &heed::RoTxn, query: &str) -> Result<QueryAst<'static>> {
+        let terms = parse_query(rtxn, index, query, None)?;
+        Ok(QueryAst { terms, filter: None })
+    }
+}
+
+/// The parameters of a search made with a [`QueryAst`].
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    pub offset: usize,
+    pub limit: usize,
+    pub terms_matching_strategy: TermsMatchingStrategy,
+    pub authorize_typos: bool,
+    pub exhaustive_number_hits: bool,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            limit: 20,
+            terms_matching_strategy: TermsMatchingStrategy::default(),
+            authorize_typos: true,
+            exhaustive_number_hits: false,
+        }
+    }
+}
+
+pub(crate) fn search_ast(
+    index: &Index,
+    rtxn: &heed::RoTxn,
+    query: &QueryAst,
+    options: &SearchOptions,
+) -> Result<SearchResult> {
+    let SearchOptions {
+        offset,
+        limit,
+        terms_matching_strategy,
+        authorize_typos,
+        exhaustive_number_hits,
+    } = *options;
+
+    let mut search = Search::new(rtxn, index);
+    search
+        .query_terms(query.terms.clone())
+        .offset(offset)
+        .limit(limit)
+        .terms_matching_strategy(terms_matching_strategy)
+        .authorize_typos(authorize_typos)
+        .exhaustive_number_hits(exhaustive_number_hits);
+    if let Some(filter) = &query.filter {
+        search.filter(filter.clone());
+    }
+    search.execute()
+}
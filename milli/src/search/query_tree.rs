@@ -13,7 +13,7 @@ use slice_group_by::GroupBy;
 
 use crate::search::matches::matching_words::{MatchingWord, PrimitiveWordId};
 use crate::search::TermsMatchingStrategy;
-use crate::{CboRoaringBitmapLenCodec, Index, MatchingWords, Result};
+use crate::{truncate_word, CboRoaringBitmapLenCodec, Index, MatchingWords, Result};
 
 type IsOptionalWord = bool;
 type IsPrefix = bool;
@@ -164,6 +164,8 @@ trait Context {
     /// Returns the minimum word len for 1 and 2 typos.
     fn min_word_len_for_typo(&self) -> heed::Result<(u8, u8)>;
     fn exact_words(&self) -> Option<&fst::Set<Cow<[u8]>>>;
+    /// Returns whether the numbers must be matched without typos.
+    fn exact_numbers(&self) -> bool;
     fn word_pair_frequency(
         &self,
         left_word: &str,
@@ -180,6 +182,8 @@ pub struct QueryTreeBuilder<'a> {
     authorize_typos: bool,
     words_limit: Option<usize>,
     exact_words: Option<fst::Set<Cow<'a, [u8]>>>,
+    exact_numbers: bool,
+    max_token_length: Option<usize>,
 }
 
 impl<'a> Context for QueryTreeBuilder<'a> {
@@ -205,6 +209,10 @@ impl<'a> Context for QueryTreeBuilder<'a> {
         self.exact_words.as_ref()
     }
 
+    fn exact_numbers(&self) -> bool {
+        self.exact_numbers
+    }
+
     fn word_pair_frequency(
         &self,
         left_word: &str,
@@ -230,6 +238,8 @@ impl<'a> QueryTreeBuilder<'a> {
             authorize_typos: true,
             words_limit: None,
             exact_words: index.exact_words(rtxn)?,
+            exact_numbers: index.exact_numbers(rtxn)?,
+            max_token_length: index.max_token_length(rtxn)?,
         })
     }
 
@@ -272,7 +282,18 @@ impl<'a> QueryTreeBuilder<'a> {
         &self,
         query: NormalizedTokenIter<A>,
     ) -> Result<Option<(Operation, PrimitiveQuery, MatchingWords)>> {
-        let primitive_query = create_primitive_query(query, self.words_limit);
+        let primitive_query =
+            create_primitive_query(query, self.words_limit, self.max_token_length);
+        self.build_from_primitive_query(primitive_query)
+    }
+
+    /// Build the query tree from the already tokenized and normalized words and phrases of a query,
+    /// see [`Self::build`]. The words and phrases beyond `words_limit` are ignored.
+    pub fn build_from_primitive_query(
+        &self,
+        mut primitive_query: PrimitiveQuery,
+    ) -> Result<Option<(Operation, PrimitiveQuery, MatchingWords)>> {
+        primitive_query.truncate(self.words_limit.unwrap_or(usize::MAX));
         if !primitive_query.is_empty() {
             let qt = create_query_tree(
                 self,
@@ -316,12 +337,18 @@ pub struct TypoConfig<'a> {
     pub word_len_one_typo: u8,
     pub word_len_two_typo: u8,
     pub exact_words: Option<&'a fst::Set<Cow<'a, [u8]>>>,
+    /// Whether the words only made of digits are matched without typos.
+    pub exact_numbers: bool,
 }
 
-/// Return the `QueryKind` of a word depending on `authorize_typos`
-/// and the provided word length.
-fn typos(word: String, authorize_typos: bool, config: TypoConfig) -> QueryKind {
-    if authorize_typos && !config.exact_words.map_or(false, |s| s.contains(&word)) {
+/// Return the `QueryKind` of a word depending on `authorize_typos`,
+/// the provided word length and whether it is a number.
+pub(crate) fn typos(word: String, authorize_typos: bool, config: TypoConfig) -> QueryKind {
+    let is_exact_number = config.exact_numbers && word.chars().all(|c| c.is_ascii_digit());
+    if authorize_typos
+        && !is_exact_number
+        && !config.exact_words.map_or(false, |s| s.contains(&word))
+    {
         let count = word.chars().count().min(u8::MAX as usize) as u8;
         if count < config.word_len_one_typo {
             QueryKind::exact(word)
@@ -384,8 +411,14 @@ fn create_query_tree(
                 }
                 let (word_len_one_typo, word_len_two_typo) = ctx.min_word_len_for_typo()?;
                 let exact_words = ctx.exact_words();
-                let config =
-                    TypoConfig { max_typos: 2, word_len_one_typo, word_len_two_typo, exact_words };
+                let exact_numbers = ctx.exact_numbers();
+                let config = TypoConfig {
+                    max_typos: 2,
+                    word_len_one_typo,
+                    word_len_two_typo,
+                    exact_words,
+                    exact_numbers,
+                };
                 children.push(Operation::Query(Query {
                     prefix,
                     kind: typos(word, authorize_typos, config),
@@ -439,11 +472,13 @@ fn create_query_tree(
                             let (word_len_one_typo, word_len_two_typo) =
                                 ctx.min_word_len_for_typo()?;
                             let exact_words = ctx.exact_words();
+                            let exact_numbers = ctx.exact_numbers();
                             let config = TypoConfig {
                                 max_typos: 1,
                                 word_len_one_typo,
                                 word_len_two_typo,
                                 exact_words,
+                                exact_numbers,
                             };
                             let query = Query {
                                 prefix: is_prefix,
@@ -613,8 +648,14 @@ fn create_matching_words(
 
                 let (word_len_one_typo, word_len_two_typo) = ctx.min_word_len_for_typo()?;
                 let exact_words = ctx.exact_words();
-                let config =
-                    TypoConfig { max_typos: 2, word_len_one_typo, word_len_two_typo, exact_words };
+                let exact_numbers = ctx.exact_numbers();
+                let config = TypoConfig {
+                    max_typos: 2,
+                    word_len_one_typo,
+                    word_len_two_typo,
+                    exact_words,
+                    exact_numbers,
+                };
 
                 let matching_word = match typos(word, authorize_typos, config) {
                     QueryKind::Exact { word, .. } => matching_word_cache.insert(word, 0, prefix),
@@ -706,11 +747,13 @@ fn create_matching_words(
                             let (word_len_one_typo, word_len_two_typo) =
                                 ctx.min_word_len_for_typo()?;
                             let exact_words = ctx.exact_words();
+                            let exact_numbers = ctx.exact_numbers();
                             let config = TypoConfig {
                                 max_typos: 1,
                                 word_len_one_typo,
                                 word_len_two_typo,
                                 exact_words,
+                                exact_numbers,
                             };
                             let matching_word = match typos(word, authorize_typos, config) {
                                 QueryKind::Exact { word, .. } => {
@@ -752,9 +795,12 @@ fn create_matching_words(
 
 pub type PrimitiveQuery = Vec<PrimitiveQueryPart>;
 
-#[derive(Debug, Clone)]
+/// A word or a phrase of a query, once tokenized and normalized.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PrimitiveQueryPart {
+    /// Consecutive words, the stop words are `None`.
     Phrase(Vec<Option<String>>),
+    /// A word that can also match as a prefix.
     Word(String, IsPrefix),
 }
 
@@ -777,9 +823,11 @@ impl PrimitiveQueryPart {
 
 /// Create primitive query from tokenized query string,
 /// the primitive query is an intermediate state to build the query tree.
-fn create_primitive_query<A>(
+/// The words longer than `max_token_length` are truncated, as they are when indexed.
+pub(crate) fn create_primitive_query<A>(
     query: NormalizedTokenIter<A>,
     words_limit: Option<usize>,
+    max_token_length: Option<usize>,
 ) -> PrimitiveQuery
 where
     A: AsRef<[u8]>,
@@ -803,6 +851,11 @@ where
 
         match token.kind {
             TokenKind::Word | TokenKind::StopWord => {
+                let word = match max_token_length {
+                    Some(max_token_length) => truncate_word(token.lemma(), max_token_length),
+                    None => token.lemma(),
+                };
+
                 // 1. if the word is quoted we push it in a phrase-buffer waiting for the ending quote,
                 // 2. if the word is not the last token of the query and is not a stop_word we push it as a non-prefix word,
                 // 3. if the word is the last token of the query we push it as a prefix word.
@@ -810,16 +863,15 @@ where
                     if let TokenKind::StopWord = token.kind {
                         phrase.push(None)
                     } else {
-                        phrase.push(Some(token.lemma().to_string()));
+                        phrase.push(Some(word.to_string()));
                     }
                 } else if peekable.peek().is_some() {
                     if let TokenKind::StopWord = token.kind {
                     } else {
-                        primitive_query
-                            .push(PrimitiveQueryPart::Word(token.lemma().to_string(), false));
+                        primitive_query.push(PrimitiveQueryPart::Word(word.to_string(), false));
                     }
                 } else {
-                    primitive_query.push(PrimitiveQueryPart::Word(token.lemma().to_string(), true));
+                    primitive_query.push(PrimitiveQueryPart::Word(word.to_string(), true));
                 }
             }
             TokenKind::Separator(separator_kind) => {
@@ -902,7 +954,7 @@ mod test {
             words_limit: Option<usize>,
             query: NormalizedTokenIter<A>,
         ) -> Result<Option<(Operation, PrimitiveQuery)>> {
-            let primitive_query = create_primitive_query(query, words_limit);
+            let primitive_query = create_primitive_query(query, words_limit, None);
             if !primitive_query.is_empty() {
                 let qt = create_query_tree(
                     self,
@@ -935,6 +987,10 @@ mod test {
             self.exact_words.as_ref()
         }
 
+        fn exact_numbers(&self) -> bool {
+            true
+        }
+
         fn word_pair_frequency(
             &self,
             left_word: &str,
@@ -1358,6 +1414,7 @@ mod test {
             word_len_one_typo: 5,
             word_len_two_typo: 7,
             exact_words: Some(&exact_words),
+            exact_numbers: true,
         };
 
         assert_eq!(
@@ -1374,6 +1431,17 @@ mod test {
             typos("verylongword".to_string(), true, config.clone()),
             QueryKind::Tolerant { typo: 2, word: "verylongword".to_string() }
         );
+
+        assert_eq!(
+            typos("20240101".to_string(), true, config.clone()),
+            QueryKind::exact("20240101".to_string())
+        );
+
+        let config = TypoConfig { exact_numbers: false, ..config };
+        assert_eq!(
+            typos("20240101".to_string(), true, config),
+            QueryKind::Tolerant { typo: 2, word: "20240101".to_string() }
+        );
     }
 
     #[test]
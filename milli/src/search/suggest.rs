@@ -0,0 +1,89 @@
+use std::cmp::Reverse;
+
+use charabia::{TokenKind, TokenizerBuilder};
+
+use super::query_tree::{typos, TypoConfig};
+use super::{word_derivations, WordDerivationsCache};
+use crate::{truncate_word, Index, Result};
+
+/// Returns the query with its unknown words replaced by the most frequent words of the index
+/// that are reachable with typos, or `None` if all the words of the query are known or none of
+/// them can be corrected. The words of the returned query are normalized.
+///
+/// Nothing is suggested when the typo tolerance is disabled on the index,
+/// the search wouldn't find the suggested words either.
+pub(crate) fn suggest_correction(
+    index: &Index,
+    rtxn: &heed::RoTxn,
+    query: &str,
+) -> Result<Option<String>> {
+    if !index.authorize_typos(rtxn)? {
+        return Ok(None);
+    }
+
+    let mut tokbuilder = TokenizerBuilder::new();
+    let stop_words = index.stop_words(rtxn)?;
+    if let Some(ref stop_words) = stop_words {
+        tokbuilder.stop_words(stop_words);
+    }
+
+    let script_lang_map = index.script_language(rtxn)?;
+    if !script_lang_map.is_empty() {
+        tokbuilder.allow_list(&script_lang_map);
+    }
+
+    let word_len_one_typo = index.min_word_len_one_typo(rtxn)?;
+    let word_len_two_typo = index.min_word_len_two_typos(rtxn)?;
+    let exact_words = index.exact_words(rtxn)?;
+    let exact_numbers = index.exact_numbers(rtxn)?;
+    let max_token_length = index.max_token_length(rtxn)?;
+    let words_fst = index.words_fst(rtxn)?;
+    let mut cache = WordDerivationsCache::new();
+
+    let tokenizer = tokbuilder.build();
+    let mut corrected = false;
+    let mut words = Vec::new();
+    for token in tokenizer.tokenize(query) {
+        // the words are truncated like when they are indexed.
+        let word = match max_token_length {
+            Some(max_token_length) => truncate_word(token.lemma(), max_token_length),
+            None => token.lemma(),
+        };
+        if token.is_separator() || word.is_empty() {
+            continue;
+        }
+
+        if token.kind == TokenKind::StopWord || words_fst.contains(word) {
+            words.push(word.to_string());
+            continue;
+        }
+
+        let config = TypoConfig {
+            max_typos: 2,
+            word_len_one_typo,
+            word_len_two_typo,
+            exact_words: exact_words.as_ref(),
+            exact_numbers,
+        };
+        let max_typo = typos(word.to_string(), true, config).typo();
+
+        let mut best = None;
+        for (derived, typo) in word_derivations(word, false, max_typo, &words_fst, &mut cache)? {
+            let count = index.word_documents_count(rtxn, derived)?.unwrap_or_default();
+            let key = (count, Reverse(*typo));
+            if best.as_ref().map_or(true, |(best_key, _)| key > *best_key) {
+                best = Some((key, derived));
+            }
+        }
+
+        match best {
+            Some((_, derived)) => {
+                corrected = true;
+                words.push(derived.clone());
+            }
+            None => words.push(word.to_string()),
+        }
+    }
+
+    Ok(corrected.then(|| words.join(" ")))
+}
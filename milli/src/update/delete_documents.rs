@@ -124,9 +124,15 @@ impl<'t, 'u, 'i> DeleteDocuments<'t, 'u, 'i> {
         let mut documents_ids = self.index.documents_ids(self.wtxn)?;
         let mut soft_deleted_docids = self.index.soft_deleted_documents_ids(self.wtxn)?;
         let current_documents_ids_len = documents_ids.len();
+        let deletion_grace = self.index.deletion_grace(self.wtxn)?;
+
+        // The already deleted documents must keep their original deletion date.
+        if deletion_grace.is_some() {
+            self.to_delete_docids &= &documents_ids;
+        }
 
         // We can and must stop removing documents in a database that is empty.
-        if documents_ids.is_empty() {
+        if documents_ids.is_empty() && deletion_grace.is_none() {
             // but if there was still documents to delete we clear the database entirely
             if !soft_deleted_docids.is_empty() {
                 ClearDocuments::new(self.wtxn, self.index).execute()?;
@@ -145,7 +151,7 @@ impl<'t, 'u, 'i> DeleteDocuments<'t, 'u, 'i> {
 
         // We can execute a ClearDocuments operation when the number of documents
         // to delete is exactly the number of documents in the database.
-        if current_documents_ids_len == self.to_delete_docids.len() {
+        if current_documents_ids_len == self.to_delete_docids.len() && deletion_grace.is_none() {
             let remaining_documents = ClearDocuments::new(self.wtxn, self.index).execute()?;
             return Ok(DetailedDocumentDeletionResult {
                 deleted_documents: current_documents_ids_len,
@@ -188,32 +194,57 @@ impl<'t, 'u, 'i> DeleteDocuments<'t, 'u, 'i> {
         // deleted immediately after.
         self.index.put_soft_deleted_documents_ids(self.wtxn, &soft_deleted_docids)?;
 
-        // decide for a hard or soft deletion depending on the strategy
-        let soft_deletion = match self.strategy {
-            DeletionStrategy::Dynamic => {
-                // decide to keep the soft deleted in the DB for now if they meet 2 criteria:
-                // 1. There is less than a fixed rate of 50% of soft-deleted to actual documents, *and*
-                // 2. Soft-deleted occupy an average of less than a fixed size on disk
-
-                let size_used = self.index.used_size()?;
-                let nb_documents = self.index.number_of_documents(self.wtxn)?;
-                let nb_soft_deleted = soft_deleted_docids.len();
-
-                (nb_soft_deleted < nb_documents) && {
-                    const SOFT_DELETED_SIZE_BYTE_THRESHOLD: u64 = 1_073_741_824; // 1GiB
-
-                    // nb_documents + nb_soft_deleted !=0 because if nb_documents is 0 we short-circuit earlier, and then we moved the documents to delete
-                    // from the documents_docids to the soft_deleted_docids.
-                    let estimated_document_size = size_used / (nb_documents + nb_soft_deleted);
-                    let estimated_size_used_by_soft_deleted =
-                        estimated_document_size * nb_soft_deleted;
-                    estimated_size_used_by_soft_deleted < SOFT_DELETED_SIZE_BYTE_THRESHOLD
+        // Only the documents deleted for longer than the deletion grace can be hard deleted.
+        let mut soft_deletion_dates = self.index.soft_deletion_dates(self.wtxn)?;
+        let expired_docids = match deletion_grace {
+            Some(grace) => {
+                let now = OffsetDateTime::now_utc().unix_timestamp();
+                for docid in &self.to_delete_docids {
+                    soft_deletion_dates.insert(docid, now);
                 }
+                self.index.put_soft_deletion_dates(self.wtxn, &soft_deletion_dates)?;
+
+                let grace = i64::try_from(grace.as_secs()).unwrap_or(i64::MAX);
+                soft_deleted_docids
+                    .iter()
+                    .filter(|docid| match soft_deletion_dates.get(docid) {
+                        Some(date) => date.saturating_add(grace) <= now,
+                        None => true,
+                    })
+                    .collect()
             }
-            DeletionStrategy::AlwaysSoft => true,
-            DeletionStrategy::AlwaysHard => false,
+            None => soft_deleted_docids.clone(),
         };
 
+        // decide for a hard or soft deletion depending on the strategy
+        let soft_deletion = (deletion_grace.is_some() && expired_docids.is_empty())
+            || match self.strategy {
+                // the expired documents are always swept when there is a deletion grace.
+                DeletionStrategy::Dynamic if deletion_grace.is_some() => false,
+                DeletionStrategy::Dynamic => {
+                    // decide to keep the soft deleted in the DB for now if they meet 2 criteria:
+                    // 1. There is less than a fixed rate of 50% of soft-deleted to actual documents, *and*
+                    // 2. Soft-deleted occupy an average of less than a fixed size on disk
+
+                    let size_used = self.index.used_size()?;
+                    let nb_documents = self.index.number_of_documents(self.wtxn)?;
+                    let nb_soft_deleted = soft_deleted_docids.len();
+
+                    (nb_soft_deleted < nb_documents) && {
+                        const SOFT_DELETED_SIZE_BYTE_THRESHOLD: u64 = 1_073_741_824; // 1GiB
+
+                        // nb_documents + nb_soft_deleted !=0 because if nb_documents is 0 we short-circuit earlier, and then we moved the documents to delete
+                        // from the documents_docids to the soft_deleted_docids.
+                        let estimated_document_size = size_used / (nb_documents + nb_soft_deleted);
+                        let estimated_size_used_by_soft_deleted =
+                            estimated_document_size * nb_soft_deleted;
+                        estimated_size_used_by_soft_deleted < SOFT_DELETED_SIZE_BYTE_THRESHOLD
+                    }
+                }
+                DeletionStrategy::AlwaysSoft => true,
+                DeletionStrategy::AlwaysHard => false,
+            };
+
         if soft_deletion {
             // Keep the soft-deleted in the DB
             return Ok(DetailedDocumentDeletionResult {
@@ -223,7 +254,8 @@ impl<'t, 'u, 'i> DeleteDocuments<'t, 'u, 'i> {
             });
         }
 
-        self.to_delete_docids = soft_deleted_docids;
+        let remaining_soft_deleted_docids = soft_deleted_docids - &expired_docids;
+        self.to_delete_docids = expired_docids;
 
         let Index {
             env: _env,
@@ -523,7 +555,13 @@ impl<'t, 'u, 'i> DeleteDocuments<'t, 'u, 'i> {
             &self.to_delete_docids,
         )?;
 
-        self.index.put_soft_deleted_documents_ids(self.wtxn, &RoaringBitmap::new())?;
+        self.index.put_soft_deleted_documents_ids(self.wtxn, &remaining_soft_deleted_docids)?;
+        soft_deletion_dates.retain(|docid, _| remaining_soft_deleted_docids.contains(*docid));
+        if soft_deletion_dates.is_empty() {
+            self.index.delete_soft_deletion_dates(self.wtxn)?;
+        } else {
+            self.index.put_soft_deletion_dates(self.wtxn, &soft_deletion_dates)?;
+        }
 
         Ok(DetailedDocumentDeletionResult {
             deleted_documents: self.to_delete_docids.len(),
@@ -1231,4 +1269,51 @@ mod tests {
             DeletionStrategy::AlwaysSoft,
         );
     }
+
+    #[test]
+    fn deletion_grace_keeps_the_deleted_documents_until_it_elapses() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_deletion_grace(std::time::Duration::from_secs(3600));
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "name": "kevin" },
+                { "id": 1, "name": "kevina" },
+                { "id": 2, "name": "benoit" },
+            ]))
+            .unwrap();
+
+        let mut wtxn = index.write_txn().unwrap();
+        let deleted_internal_ids =
+            delete_documents(&mut wtxn, &index, &["1"], DeletionStrategy::AlwaysHard);
+        wtxn.commit().unwrap();
+
+        // the document is excluded from the results right away...
+        let rtxn = index.read_txn().unwrap();
+        let results = index.search(&rtxn).query("kevina").execute().unwrap();
+        assert!(results.documents_ids.iter().all(|id| !deleted_internal_ids.contains(id)));
+        assert!(!index.documents_ids(&rtxn).unwrap().contains(deleted_internal_ids[0]));
+        // ...but it is still stored during the grace period.
+        let key = BEU32::new(deleted_internal_ids[0]);
+        assert!(index.documents.get(&rtxn, &key).unwrap().is_some());
+        drop(rtxn);
+
+        // we simulate the elapsing of the grace by moving the deletion date back in time.
+        let mut wtxn = index.write_txn().unwrap();
+        let mut dates = index.soft_deletion_dates(&wtxn).unwrap();
+        dates.values_mut().for_each(|date| *date -= 7200);
+        index.put_soft_deletion_dates(&mut wtxn, &dates).unwrap();
+        // the next deletion sweeps the expired documents.
+        DeleteDocuments::new(&mut wtxn, &index).unwrap().execute().unwrap();
+        wtxn.commit().unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.documents.get(&rtxn, &key).unwrap().is_none());
+        assert!(index.soft_deleted_documents_ids(&rtxn).unwrap().is_empty());
+        assert!(index.soft_deletion_dates(&rtxn).unwrap().is_empty());
+        assert_eq!(index.number_of_documents(&rtxn).unwrap(), 2);
+    }
 }
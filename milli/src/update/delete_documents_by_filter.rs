@@ -0,0 +1,114 @@
+use roaring::RoaringBitmap;
+
+use super::{DeleteDocuments, DeletionStrategy, DocumentDeletionResult};
+use crate::{Filter, Index, Result};
+
+/// The default number of documents deleted at once by [`DeleteDocumentsByFilter`].
+const DEFAULT_BATCH_SIZE: usize = 1000;
+
+/// Deletes the documents matching a filter by batches of bounded size, calling the
+/// progress callback after each batch.
+pub struct DeleteDocumentsByFilter<'t, 'u, 'i> {
+    wtxn: &'t mut heed::RwTxn<'i, 'u>,
+    index: &'i Index,
+    batch_size: usize,
+    strategy: DeletionStrategy,
+}
+
+impl<'t, 'u, 'i> DeleteDocumentsByFilter<'t, 'u, 'i> {
+    pub fn new(
+        wtxn: &'t mut heed::RwTxn<'i, 'u>,
+        index: &'i Index,
+    ) -> DeleteDocumentsByFilter<'t, 'u, 'i> {
+        DeleteDocumentsByFilter {
+            wtxn,
+            index,
+            batch_size: DEFAULT_BATCH_SIZE,
+            strategy: Default::default(),
+        }
+    }
+
+    /// The maximum number of documents deleted by each batch, it is at least one.
+    pub fn batch_size(&mut self, batch_size: usize) {
+        self.batch_size = batch_size.max(1);
+    }
+
+    pub fn strategy(&mut self, strategy: DeletionStrategy) {
+        self.strategy = strategy;
+    }
+
+    /// Deletes the documents matching the filter and returns the number of deleted documents.
+    ///
+    /// The progress callback receives the number of documents deleted
+    /// so far and the total number of documents to delete.
+    pub fn execute<F>(self, filter: &Filter, mut progress: F) -> Result<u64>
+    where
+        F: FnMut(u64, u64),
+    {
+        let mut to_delete = filter.evaluate(self.wtxn, self.index)?;
+        to_delete -= self.index.soft_deleted_documents_ids(self.wtxn)?;
+
+        let total = to_delete.len();
+        let mut deleted = 0;
+        let mut docids = to_delete.into_iter();
+        loop {
+            let batch: RoaringBitmap = docids.by_ref().take(self.batch_size).collect();
+            if batch.is_empty() {
+                break;
+            }
+
+            let mut builder = DeleteDocuments::new(self.wtxn, self.index)?;
+            builder.strategy(self.strategy);
+            builder.delete_documents(&batch);
+            let DocumentDeletionResult { deleted_documents, .. } = builder.execute()?;
+
+            deleted += deleted_documents;
+            progress(deleted, total);
+        }
+
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use big_s::S;
+    use maplit::hashset;
+
+    use super::*;
+    use crate::documents::documents_batch_reader_from_objects;
+    use crate::index::tests::TempIndex;
+
+    #[test]
+    fn delete_documents_by_filter_in_batches() {
+        let index = TempIndex::new_with_map_size(4096 * 10_000);
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("id") });
+            })
+            .unwrap();
+
+        let documents = (0..5000i32).map(|i| {
+            let json = serde_json::json!({ "id": i, "text": "hello" });
+            match json {
+                serde_json::Value::Object(object) => object,
+                _ => unreachable!(),
+            }
+        });
+        index.add_documents(documents_batch_reader_from_objects(documents)).unwrap();
+
+        let mut wtxn = index.write_txn().unwrap();
+        let filter = Filter::from_str("id >= 1500").unwrap().unwrap();
+        let mut builder = DeleteDocumentsByFilter::new(&mut wtxn, &index);
+        builder.batch_size(1000);
+
+        let mut progress = Vec::new();
+        let deleted =
+            builder.execute(&filter, |deleted, total| progress.push((deleted, total))).unwrap();
+
+        assert_eq!(deleted, 3500);
+        assert_eq!(progress, vec![(1000, 3500), (2000, 3500), (3000, 3500), (3500, 3500)]);
+        assert_eq!(index.number_of_documents(&wtxn).unwrap(), 1500);
+        wtxn.commit().unwrap();
+    }
+}
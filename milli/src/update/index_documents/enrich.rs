@@ -8,7 +8,9 @@ use serde_json::Value;
 use crate::documents::{DocumentsBatchIndex, DocumentsBatchReader, EnrichedDocumentsBatchReader};
 use crate::error::{GeoError, InternalError, UserError};
 use crate::update::index_documents::{obkv_to_object, writer_into_reader};
-use crate::{FieldId, Index, Object, Result};
+use crate::{
+    FieldId, Index, Object, Result, DEFAULT_MAX_DOCUMENT_ID_LENGTH, RESERVED_BOOST_FIELD_NAME,
+};
 
 /// The symbol used to define levels in a nested primary key.
 const PRIMARY_KEY_SPLIT_SYMBOL: char = '.';
@@ -20,7 +22,8 @@ const DEFAULT_PRIMARY_KEY: &str = "id";
 ///  - we can infer a primary key,
 ///  - all the documents id exist and are extracted,
 ///  - the validity of them but also,
-///  - the validity of the `_geo` field depending on the settings.
+///  - the validity of the `_geo` field depending on the settings,
+///  - the validity of the reserved `_boost` field.
 ///
 /// # Panics
 ///
@@ -107,12 +110,19 @@ pub fn enrich_documents_batch<R: Read + Seek>(
         _otherwise => None,
     };
 
+    // The `_boost` field is reserved whatever the settings are.
+    let boost_field_id = documents_batch_index.id(RESERVED_BOOST_FIELD_NAME);
+
+    let max_document_id_length =
+        index.max_document_id_length(rtxn)?.unwrap_or(DEFAULT_MAX_DOCUMENT_ID_LENGTH);
+
     let mut count = 0;
     while let Some(document) = cursor.next_document()? {
         let document_id = match fetch_or_generate_document_id(
             &document,
             &documents_batch_index,
             primary_key,
+            max_document_id_length,
             autogenerate_docids,
             &mut uuid_buffer,
             count,
@@ -127,6 +137,12 @@ pub fn enrich_documents_batch<R: Read + Seek>(
             }
         }
 
+        if let Some(boost_value) = boost_field_id.and_then(|fid| document.get(fid)) {
+            if let Err(user_error) = validate_boost_from_json(&document_id, boost_value)? {
+                return Ok(Err(user_error));
+            }
+        }
+
         let document_id = serde_json::to_vec(&document_id).map_err(InternalError::SerdeJson)?;
         external_ids.insert(count.to_be_bytes(), document_id)?;
 
@@ -150,6 +166,7 @@ fn fetch_or_generate_document_id(
     document: &obkv::KvReader<FieldId>,
     documents_batch_index: &DocumentsBatchIndex,
     primary_key: PrimaryKey,
+    max_document_id_length: usize,
     autogenerate_docids: bool,
     uuid_buffer: &mut [u8; uuid::fmt::Hyphenated::LENGTH],
     count: u32,
@@ -160,7 +177,7 @@ fn fetch_or_generate_document_id(
                 Some(document_id_bytes) => {
                     let document_id = serde_json::from_slice(document_id_bytes)
                         .map_err(InternalError::SerdeJson)?;
-                    match validate_document_id_value(document_id)? {
+                    match validate_document_id_value(document_id, max_document_id_length)? {
                         Ok(document_id) => Ok(Ok(DocumentId::retrieved(document_id))),
                         Err(user_error) => Ok(Err(user_error)),
                     }
@@ -195,10 +212,12 @@ fn fetch_or_generate_document_id(
             }
 
             match matching_documents_ids.pop() {
-                Some(document_id) => match validate_document_id_value(document_id)? {
-                    Ok(document_id) => Ok(Ok(DocumentId::retrieved(document_id))),
-                    Err(user_error) => Ok(Err(user_error)),
-                },
+                Some(document_id) => {
+                    match validate_document_id_value(document_id, max_document_id_length)? {
+                        Ok(document_id) => Ok(Ok(DocumentId::retrieved(document_id))),
+                        Err(user_error) => Ok(Err(user_error)),
+                    }
+                }
                 None => Ok(Err(UserError::MissingDocumentId {
                     primary_key: nested.name().to_string(),
                     document: obkv_to_object(document, documents_batch_index)?,
@@ -336,8 +355,14 @@ pub fn validate_document_id(document_id: &str) -> Option<&str> {
 }
 
 /// Parses a Json encoded document id and validate it, returning a user error when it is one.
-pub fn validate_document_id_value(document_id: Value) -> Result<StdResult<String, UserError>> {
+pub fn validate_document_id_value(
+    document_id: Value,
+    max_length: usize,
+) -> Result<StdResult<String, UserError>> {
     match document_id {
+        Value::String(string) if string.len() > max_length => {
+            Ok(Err(UserError::DocumentIdTooLong { document_id: Value::String(string), max_length }))
+        }
         Value::String(string) => match validate_document_id(&string) {
             Some(s) if s.len() == string.len() => Ok(Ok(string)),
             Some(s) => Ok(Ok(s.to_string())),
@@ -399,3 +424,18 @@ pub fn validate_geo_from_json(id: &DocumentId, bytes: &[u8]) -> Result<StdResult
         value => Ok(Err(NotAnObject { document_id: debug_id(), value })),
     }
 }
+
+/// Checks that the `_boost` field is a positive finite number, or null.
+pub fn validate_boost_from_json(id: &DocumentId, bytes: &[u8]) -> Result<StdResult<(), UserError>> {
+    match serde_json::from_slice(bytes).map_err(InternalError::SerdeJson)? {
+        Value::Null => Ok(Ok(())),
+        Value::Number(number) if number.as_f64().map_or(false, |n| n.is_finite() && n >= 0.0) => {
+            Ok(Ok(()))
+        }
+        value => {
+            let document_id = serde_json::from_slice(id.value().as_bytes())
+                .unwrap_or_else(|_| Value::from(id.debug()));
+            Ok(Err(UserError::InvalidDocumentBoost { document_id, value }))
+        }
+    }
+}
@@ -12,7 +12,8 @@ use super::helpers::{concat_u32s_array, create_sorter, sorter_into_reader, Grena
 use crate::error::{InternalError, SerializationError};
 use crate::update::index_documents::MergeFn;
 use crate::{
-    absolute_from_relative_position, FieldId, Result, MAX_POSITION_PER_ATTRIBUTE, MAX_WORD_LENGTH,
+    absolute_from_relative_position, truncate_word, FieldId, Result, MAX_POSITION_PER_ATTRIBUTE,
+    MAX_WORD_LENGTH,
 };
 
 pub type ScriptLanguageDocidsMap = HashMap<(Script, Language), RoaringBitmap>;
@@ -29,6 +30,7 @@ pub fn extract_docid_word_positions<R: io::Read + io::Seek>(
     searchable_fields: &Option<HashSet<FieldId>>,
     stop_words: Option<&fst::Set<&[u8]>>,
     max_positions_per_attributes: Option<u32>,
+    max_token_length: Option<usize>,
 ) -> Result<(RoaringBitmap, grenad::Reader<File>, ScriptLanguageDocidsMap)> {
     let max_positions_per_attributes = max_positions_per_attributes
         .map_or(MAX_POSITION_PER_ATTRIBUTE, |max| max.min(MAX_POSITION_PER_ATTRIBUTE));
@@ -71,6 +73,7 @@ pub fn extract_docid_word_positions<R: io::Read + io::Seek>(
             searchable_fields,
             &tokenizer,
             max_positions_per_attributes,
+            max_token_length,
             &mut buffers,
             &mut script_language_word_count,
             &mut docid_word_positions_sorter,
@@ -107,6 +110,7 @@ pub fn extract_docid_word_positions<R: io::Read + io::Seek>(
                     searchable_fields,
                     &tokenizer,
                     max_positions_per_attributes,
+                    max_token_length,
                     &mut buffers,
                     &mut script_language_word_count,
                     &mut docid_word_positions_sorter,
@@ -128,11 +132,13 @@ pub fn extract_docid_word_positions<R: io::Read + io::Seek>(
         .map(|reader| (documents_ids, reader, script_language_docids))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn extract_tokens_from_document<T: AsRef<[u8]>>(
     obkv: &KvReader<FieldId>,
     searchable_fields: &Option<HashSet<FieldId>>,
     tokenizer: &Tokenizer<T>,
     max_positions_per_attributes: u32,
+    max_token_length: Option<usize>,
     buffers: &mut Buffers,
     script_language_word_count: &mut HashMap<Script, Vec<(Language, usize)>>,
     docid_word_positions_sorter: &mut grenad::Sorter<MergeFn>,
@@ -156,7 +162,10 @@ fn extract_tokens_from_document<T: AsRef<[u8]>>(
                             None => entry.push((language, 1)),
                         }
                     }
-                    let token = token.lemma().trim();
+                    let mut token = token.lemma().trim();
+                    if let Some(max_token_length) = max_token_length {
+                        token = truncate_word(token, max_token_length);
+                    }
                     if !token.is_empty() && token.len() <= MAX_WORD_LENGTH {
                         buffers.key_buffer.truncate(mem::size_of::<u32>());
                         buffers.key_buffer.extend_from_slice(token.as_bytes());
@@ -45,6 +45,7 @@ pub(crate) fn data_from_obkv_documents(
     geo_fields_ids: Option<(FieldId, FieldId)>,
     stop_words: Option<fst::Set<&[u8]>>,
     max_positions_per_attributes: Option<u32>,
+    max_token_length: Option<usize>,
     exact_attributes: HashSet<FieldId>,
 ) -> Result<()> {
     original_obkv_chunks
@@ -68,6 +69,7 @@ pub(crate) fn data_from_obkv_documents(
                 geo_fields_ids,
                 &stop_words,
                 max_positions_per_attributes,
+                max_token_length,
             )
         })
         .collect();
@@ -231,6 +233,7 @@ fn send_and_extract_flattened_documents_data(
     geo_fields_ids: Option<(FieldId, FieldId)>,
     stop_words: &Option<fst::Set<&[u8]>>,
     max_positions_per_attributes: Option<u32>,
+    max_token_length: Option<usize>,
 ) -> Result<(
     grenad::Reader<CursorClonableMmap>,
     (
@@ -264,6 +267,7 @@ fn send_and_extract_flattened_documents_data(
                         searchable_fields,
                         stop_words.as_ref(),
                         max_positions_per_attributes,
+                        max_token_length,
                     )?;
 
                 // send documents_ids to DB writer
@@ -306,6 +306,7 @@ where
         };
 
         let stop_words = self.index.stop_words(self.wtxn)?;
+        let max_token_length = self.index.max_token_length(self.wtxn)?;
         let exact_attributes = self.index.exact_attributes_ids(self.wtxn)?;
 
         let pool_params = GrenadParameters {
@@ -342,6 +343,7 @@ where
                     geo_fields_ids,
                     stop_words,
                     max_positions_per_attributes,
+                    max_token_length,
                     exact_attributes,
                 )
             });
@@ -660,7 +662,7 @@ mod tests {
     use crate::index::tests::TempIndex;
     use crate::search::TermsMatchingStrategy;
     use crate::update::DeleteDocuments;
-    use crate::{db_snap, BEU16};
+    use crate::{db_snap, BEU16, DEFAULT_MAX_DOCUMENT_ID_LENGTH};
 
     #[test]
     fn simple_document_replacement() {
@@ -882,6 +884,18 @@ mod tests {
         // There is a space in the document id.
         index.add_documents(documents!([ { "id": "brume bleue", "name": "kevin" } ])).unwrap_err();
 
+        // Then we send 1 document with an id that is too long.
+        let id = "a".repeat(DEFAULT_MAX_DOCUMENT_ID_LENGTH + 1);
+        let error =
+            index.add_documents(documents!([ { "id": &id, "name": "kevin" } ])).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UserError(UserError::DocumentIdTooLong {
+                max_length: DEFAULT_MAX_DOCUMENT_ID_LENGTH,
+                ..
+            })
+        ));
+
         // Then we send 1 document with a valid id.
         index.add_documents(documents!([ { "id": 32, "name": "kevin" } ])).unwrap();
 
@@ -890,6 +904,19 @@ mod tests {
         let count = index.number_of_documents(&rtxn).unwrap();
         assert_eq!(count, 1);
         drop(rtxn);
+
+        // The long id is accepted once the limit is raised.
+        index
+            .update_settings(|settings| {
+                settings.set_max_document_id_length(DEFAULT_MAX_DOCUMENT_ID_LENGTH * 2);
+            })
+            .unwrap();
+        index.add_documents(documents!([ { "id": id, "name": "kevin" } ])).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let count = index.number_of_documents(&rtxn).unwrap();
+        assert_eq!(count, 2);
+        drop(rtxn);
     }
 
     #[test]
@@ -806,14 +806,18 @@ fn drop_and_reuse<U, T>(mut vec: Vec<U>) -> Vec<T> {
 }
 
 impl TransformOutput {
-    // find and insert the new field ids
+    // find and insert the new field ids, the `_boost` field is always faceted
+    // to retrieve the boost of the documents at search time.
     pub fn compute_real_facets(&self, rtxn: &RoTxn, index: &Index) -> Result<HashSet<String>> {
         let user_defined_facets = index.user_defined_faceted_fields(rtxn)?;
 
         Ok(self
             .fields_ids_map
             .names()
-            .filter(|&field| crate::is_faceted(field, &user_defined_facets))
+            .filter(|&field| {
+                field == crate::RESERVED_BOOST_FIELD_NAME
+                    || crate::is_faceted(field, &user_defined_facets)
+            })
             .map(|field| field.to_string())
             .collect())
     }
@@ -1,6 +1,7 @@
 pub use self::available_documents_ids::AvailableDocumentsIds;
 pub use self::clear_documents::ClearDocuments;
 pub use self::delete_documents::{DeleteDocuments, DeletionStrategy, DocumentDeletionResult};
+pub use self::delete_documents_by_filter::DeleteDocumentsByFilter;
 pub use self::facet::bulk::FacetsUpdateBulk;
 pub use self::facet::incremental::FacetsUpdateIncrementalInner;
 pub use self::index_documents::{
@@ -20,6 +21,7 @@ pub use self::words_prefixes_fst::WordsPrefixesFst;
 mod available_documents_ids;
 mod clear_documents;
 mod delete_documents;
+mod delete_documents_by_filter;
 pub(crate) mod facet;
 mod index_documents;
 mod indexer_config;
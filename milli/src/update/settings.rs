@@ -1,5 +1,6 @@
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::result::Result as StdResult;
+use std::time::Duration;
 
 use charabia::{Tokenizer, TokenizerBuilder};
 use deserr::{DeserializeError, Deserr};
@@ -14,7 +15,7 @@ use crate::error::UserError;
 use crate::index::{DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS};
 use crate::update::index_documents::IndexDocumentsMethod;
 use crate::update::{IndexDocuments, UpdateIndexingStep};
-use crate::{FieldsIdsMap, Index, Result};
+use crate::{truncate_word, FieldsIdsMap, Index, Result};
 
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub enum Setting<T> {
@@ -116,13 +117,22 @@ pub struct Settings<'a, 't, 'u, 'i> {
     synonyms: Setting<HashMap<String, Vec<String>>>,
     primary_key: Setting<String>,
     authorize_typos: Setting<bool>,
+    exact_numbers: Setting<bool>,
     min_word_len_two_typos: Setting<u8>,
     min_word_len_one_typo: Setting<u8>,
     exact_words: Setting<BTreeSet<String>>,
     /// Attributes on which typo tolerance is disabled.
     exact_attributes: Setting<HashSet<String>>,
+    /// Attributes on which the last query word is only matched as a whole word.
+    non_prefix_attributes: Setting<HashSet<String>>,
+    /// Minimum number of query words matching in an attribute for its ranking boost to apply.
+    attributes_coverage: Setting<BTreeMap<String, usize>>,
     max_values_per_facet: Setting<usize>,
     pagination_max_total_hits: Setting<usize>,
+    max_token_length: Setting<usize>,
+    max_document_id_length: Setting<usize>,
+    deletion_grace: Setting<Duration>,
+    min_exact_results: Setting<usize>,
 }
 
 impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
@@ -144,12 +154,19 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
             synonyms: Setting::NotSet,
             primary_key: Setting::NotSet,
             authorize_typos: Setting::NotSet,
+            exact_numbers: Setting::NotSet,
             exact_words: Setting::NotSet,
             min_word_len_two_typos: Setting::NotSet,
             min_word_len_one_typo: Setting::NotSet,
             exact_attributes: Setting::NotSet,
+            non_prefix_attributes: Setting::NotSet,
+            attributes_coverage: Setting::NotSet,
             max_values_per_facet: Setting::NotSet,
             pagination_max_total_hits: Setting::NotSet,
+            max_token_length: Setting::NotSet,
+            max_document_id_length: Setting::NotSet,
+            deletion_grace: Setting::NotSet,
+            min_exact_results: Setting::NotSet,
             indexer_config,
         }
     }
@@ -235,6 +252,14 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         self.authorize_typos = Setting::Reset;
     }
 
+    pub fn set_exact_numbers(&mut self, val: bool) {
+        self.exact_numbers = Setting::Set(val);
+    }
+
+    pub fn reset_exact_numbers(&mut self) {
+        self.exact_numbers = Setting::Reset;
+    }
+
     pub fn set_min_word_len_two_typos(&mut self, val: u8) {
         self.min_word_len_two_typos = Setting::Set(val);
     }
@@ -267,6 +292,27 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         self.exact_attributes = Setting::Reset;
     }
 
+    /// Sets the attributes in which the last word of the queries isn't completed,
+    /// "cat" only matches the word "cat" there and not "category".
+    pub fn set_non_prefix_attributes(&mut self, attrs: HashSet<String>) {
+        self.non_prefix_attributes = Setting::Set(attrs);
+    }
+
+    pub fn reset_non_prefix_attributes(&mut self) {
+        self.non_prefix_attributes = Setting::Reset;
+    }
+
+    /// Sets the minimum number of query words that must match in an attribute for the
+    /// attribute ranking rule to take it into account. The words only found in attributes
+    /// that are not covered enough are ranked after all the other matches.
+    pub fn set_attributes_coverage(&mut self, coverage: BTreeMap<String, usize>) {
+        self.attributes_coverage = Setting::Set(coverage);
+    }
+
+    pub fn reset_attributes_coverage(&mut self) {
+        self.attributes_coverage = Setting::Reset;
+    }
+
     pub fn set_max_values_per_facet(&mut self, value: usize) {
         self.max_values_per_facet = Setting::Set(value);
     }
@@ -283,6 +329,51 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         self.pagination_max_total_hits = Setting::Reset;
     }
 
+    /// Sets the number of characters the words are truncated to before being indexed.
+    /// The stored synonyms are normalized again with the new limit.
+    ///
+    /// There is no limit by default: the existing indexes were built with untruncated
+    /// words and the queries must be truncated exactly like the indexed words to match
+    /// them. The words over [`crate::MAX_WORD_LENGTH`] bytes are never indexed anyway.
+    pub fn set_max_token_length(&mut self, value: usize) {
+        self.max_token_length = Setting::Set(value);
+    }
+
+    pub fn reset_max_token_length(&mut self) {
+        self.max_token_length = Setting::Reset;
+    }
+
+    /// Sets the maximum length, in bytes, of the identifiers of the documents added from now on,
+    /// the documents already in the index are kept even if their identifier is longer.
+    pub fn set_max_document_id_length(&mut self, value: usize) {
+        self.max_document_id_length = Setting::Set(value);
+    }
+
+    pub fn reset_max_document_id_length(&mut self) {
+        self.max_document_id_length = Setting::Reset;
+    }
+
+    /// Sets the duration during which the deleted documents are kept in the database. They are
+    /// excluded from the results right away and definitely removed by the first deletion
+    /// happening after the grace period, or when all the documents are reindexed.
+    pub fn set_deletion_grace(&mut self, grace: Duration) {
+        self.deletion_grace = Setting::Set(grace);
+    }
+
+    pub fn reset_deletion_grace(&mut self) {
+        self.deletion_grace = Setting::Reset;
+    }
+
+    /// Sets the number of documents the query must match without typos nor prefixes for
+    /// the search to skip the typo and prefix derivations of the query words.
+    pub fn set_min_exact_results(&mut self, value: usize) {
+        self.min_exact_results = Setting::Set(value);
+    }
+
+    pub fn reset_min_exact_results(&mut self) {
+        self.min_exact_results = Setting::Reset;
+    }
+
     fn reindex<FP, FA>(
         &mut self,
         progress_callback: &FP,
@@ -433,59 +524,99 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         }
     }
 
+    /// Whether the max token length stored in the index is modified by this update.
+    fn max_token_length_changed(&self) -> Result<bool> {
+        let old = self.index.max_token_length(self.wtxn)?;
+        Ok(match self.max_token_length {
+            Setting::Set(max) => old != Some(max),
+            Setting::Reset => old.is_some(),
+            Setting::NotSet => false,
+        })
+    }
+
     fn update_synonyms(&mut self) -> Result<bool> {
-        match self.synonyms {
-            Setting::Set(ref synonyms) => {
-                fn normalize(tokenizer: &Tokenizer<&[u8]>, text: &str) -> Vec<String> {
-                    tokenizer
-                        .tokenize(text)
-                        .filter_map(|token| {
-                            if token.is_word() {
-                                Some(token.lemma().to_string())
-                            } else {
-                                None
-                            }
+        let synonyms = match self.synonyms {
+            Setting::Set(ref synonyms) => synonyms.clone(),
+            Setting::Reset => {
+                self.index.delete_user_defined_synonyms(self.wtxn)?;
+                return Ok(self.index.delete_synonyms(self.wtxn)?);
+            }
+            // The synonyms are stored truncated to the max token length,
+            // they are normalized again when it changes.
+            Setting::NotSet if self.max_token_length_changed()? => {
+                match self.index.user_defined_synonyms(self.wtxn)? {
+                    Some(synonyms) => synonyms,
+                    // The synonyms set by an older version are only kept normalized,
+                    // they are normalized again from their words.
+                    None => self
+                        .index
+                        .synonyms(self.wtxn)?
+                        .into_iter()
+                        .map(|(word, synonyms)| {
+                            let synonyms = synonyms.iter().map(|s| s.join(" ")).collect();
+                            (word.join(" "), synonyms)
                         })
-                        .collect::<Vec<_>>()
+                        .collect(),
                 }
+            }
+            Setting::NotSet => return Ok(false),
+        };
+
+        fn normalize(tokenizer: &Tokenizer<&[u8]>, text: &str) -> Vec<String> {
+            tokenizer
+                .tokenize(text)
+                .filter(|token| token.is_word())
+                .map(|token| token.lemma().to_string())
+                .collect::<Vec<_>>()
+        }
 
-                let mut builder = TokenizerBuilder::new();
-                let stop_words = self.index.stop_words(self.wtxn)?;
-                if let Some(ref stop_words) = stop_words {
-                    builder.stop_words(stop_words);
-                }
-                let tokenizer = builder.build();
-
-                let mut new_synonyms = HashMap::new();
-                for (word, synonyms) in synonyms {
-                    // Normalize both the word and associated synonyms.
-                    let normalized_word = normalize(&tokenizer, word);
-                    let normalized_synonyms =
-                        synonyms.iter().map(|synonym| normalize(&tokenizer, synonym));
-
-                    // Store the normalized synonyms under the normalized word,
-                    // merging the possible duplicate words.
-                    let entry = new_synonyms.entry(normalized_word).or_insert_with(Vec::new);
-                    entry.extend(normalized_synonyms);
-                }
+        let mut builder = TokenizerBuilder::new();
+        let stop_words = self.index.stop_words(self.wtxn)?;
+        if let Some(ref stop_words) = stop_words {
+            builder.stop_words(stop_words);
+        }
+        let tokenizer = builder.build();
+
+        // The synonyms are truncated like the words of the documents and the queries.
+        let max_token_length = match self.max_token_length {
+            Setting::Set(max) => Some(max),
+            Setting::Reset => None,
+            Setting::NotSet => self.index.max_token_length(self.wtxn)?,
+        };
+        let truncate = |words: Vec<String>| -> Vec<String> {
+            match max_token_length {
+                Some(max) => words.iter().map(|w| truncate_word(w, max).into()).collect(),
+                None => words,
+            }
+        };
+
+        let mut new_synonyms = HashMap::new();
+        for (word, synonyms) in &synonyms {
+            // Normalize both the word and associated synonyms.
+            let normalized_word = truncate(normalize(&tokenizer, word));
+            let normalized_synonyms =
+                synonyms.iter().map(|synonym| truncate(normalize(&tokenizer, synonym)));
+
+            // Store the normalized synonyms under the normalized word,
+            // merging the possible duplicate words.
+            let entry = new_synonyms.entry(normalized_word).or_insert_with(Vec::new);
+            entry.extend(normalized_synonyms);
+        }
 
-                // Make sure that we don't have duplicate synonyms.
-                new_synonyms.iter_mut().for_each(|(_, synonyms)| {
-                    synonyms.sort_unstable();
-                    synonyms.dedup();
-                });
+        // Make sure that we don't have duplicate synonyms.
+        new_synonyms.iter_mut().for_each(|(_, synonyms)| {
+            synonyms.sort_unstable();
+            synonyms.dedup();
+        });
 
-                let old_synonyms = self.index.synonyms(self.wtxn)?;
+        self.index.put_user_defined_synonyms(self.wtxn, &synonyms)?;
+        let old_synonyms = self.index.synonyms(self.wtxn)?;
 
-                if new_synonyms != old_synonyms {
-                    self.index.put_synonyms(self.wtxn, &new_synonyms)?;
-                    Ok(true)
-                } else {
-                    Ok(false)
-                }
-            }
-            Setting::Reset => Ok(self.index.delete_synonyms(self.wtxn)?),
-            Setting::NotSet => Ok(false),
+        if new_synonyms != old_synonyms {
+            self.index.put_synonyms(self.wtxn, &new_synonyms)?;
+            Ok(true)
+        } else {
+            Ok(false)
         }
     }
 
@@ -508,6 +639,35 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         }
     }
 
+    fn update_non_prefix_attributes(&mut self) -> Result<()> {
+        match self.non_prefix_attributes {
+            Setting::Set(ref attrs) => {
+                let attrs = attrs.iter().map(String::as_str).collect::<Vec<_>>();
+                self.index.put_non_prefix_attributes(self.wtxn, &attrs)?;
+            }
+            Setting::Reset => {
+                self.index.delete_non_prefix_attributes(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+
+        Ok(())
+    }
+
+    fn update_attributes_coverage(&mut self) -> Result<()> {
+        match self.attributes_coverage {
+            Setting::Set(ref coverage) => {
+                self.index.put_attributes_coverage(self.wtxn, coverage)?;
+            }
+            Setting::Reset => {
+                self.index.delete_attributes_coverage(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+
+        Ok(())
+    }
+
     fn update_filterable(&mut self) -> Result<()> {
         match self.filterable_fields {
             Setting::Set(ref fields) => {
@@ -600,6 +760,20 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         }
     }
 
+    fn update_exact_numbers(&mut self) -> Result<()> {
+        match self.exact_numbers {
+            Setting::Set(flag) => {
+                self.index.put_exact_numbers(self.wtxn, flag)?;
+                Ok(())
+            }
+            Setting::Reset => {
+                self.index.put_exact_numbers(self.wtxn, true)?;
+                Ok(())
+            }
+            Setting::NotSet => Ok(()),
+        }
+    }
+
     fn update_min_typo_word_len(&mut self) -> Result<()> {
         let one = self.min_word_len_one_typo.or_reset(DEFAULT_MIN_WORD_LEN_ONE_TYPO);
         let two = self.min_word_len_two_typos.or_reset(DEFAULT_MIN_WORD_LEN_TWO_TYPOS);
@@ -694,6 +868,65 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         Ok(())
     }
 
+    fn update_max_document_id_length(&mut self) -> Result<()> {
+        match self.max_document_id_length {
+            Setting::Set(0) => return Err(UserError::InvalidMaxDocumentIdLengthSetting.into()),
+            Setting::Set(max) => {
+                self.index.put_max_document_id_length(self.wtxn, max)?;
+            }
+            Setting::Reset => {
+                self.index.delete_max_document_id_length(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+
+        Ok(())
+    }
+
+    fn update_deletion_grace(&mut self) -> Result<()> {
+        match self.deletion_grace {
+            Setting::Set(grace) => {
+                self.index.put_deletion_grace(self.wtxn, grace)?;
+            }
+            Setting::Reset => {
+                self.index.delete_deletion_grace(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+
+        Ok(())
+    }
+
+    fn update_min_exact_results(&mut self) -> Result<()> {
+        match self.min_exact_results {
+            Setting::Set(min) => {
+                self.index.put_min_exact_results(self.wtxn, min)?;
+            }
+            Setting::Reset => {
+                self.index.delete_min_exact_results(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+
+        Ok(())
+    }
+
+    fn update_max_token_length(&mut self) -> Result<bool> {
+        match self.max_token_length {
+            Setting::Set(0) => Err(UserError::InvalidMaxTokenLengthSetting.into()),
+            Setting::Set(max) => {
+                if self.index.max_token_length(self.wtxn)? != Some(max) {
+                    self.index.put_max_token_length(self.wtxn, max)?;
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            Setting::Reset => Ok(self.index.delete_max_token_length(self.wtxn)?),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
     pub fn execute<FP, FA>(mut self, progress_callback: FP, should_abort: FA) -> Result<()>
     where
         FP: Fn(UpdateIndexingStep) + Sync,
@@ -711,10 +944,16 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         self.update_criteria()?;
         self.update_primary_key()?;
         self.update_authorize_typos()?;
+        self.update_exact_numbers()?;
         self.update_min_typo_word_len()?;
         self.update_exact_words()?;
+        self.update_non_prefix_attributes()?;
+        self.update_attributes_coverage()?;
         self.update_max_values_per_facet()?;
         self.update_pagination_max_total_hits()?;
+        self.update_max_document_id_length()?;
+        self.update_deletion_grace()?;
+        self.update_min_exact_results()?;
 
         // If there is new faceted fields we indicate that we must reindex as we must
         // index new fields as facets. It means that the distinct attribute,
@@ -726,12 +965,14 @@ impl<'a, 't, 'u, 'i> Settings<'a, 't, 'u, 'i> {
         let synonyms_updated = self.update_synonyms()?;
         let searchable_updated = self.update_searchable()?;
         let exact_attributes_updated = self.update_exact_attributes()?;
+        let max_token_length_updated = self.update_max_token_length()?;
 
         if stop_words_updated
             || faceted_updated
             || synonyms_updated
             || searchable_updated
             || exact_attributes_updated
+            || max_token_length_updated
         {
             self.reindex(&progress_callback, &should_abort, old_fields_ids_map)?;
         }
@@ -1253,6 +1494,33 @@ mod tests {
         assert!(result.documents_ids.is_empty());
     }
 
+    #[test]
+    fn synonyms_are_normalized() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "a cup of coffee" },
+                { "id": 1, "text": "a cup of tea" },
+            ]))
+            .unwrap();
+
+        index
+            .update_settings(|settings| {
+                settings.set_synonyms(hashmap! { S("Café") => vec![S("Coffee")] });
+            })
+            .unwrap();
+
+        // The synonyms are stored normalized like the indexed words
+        let rtxn = index.read_txn().unwrap();
+        let synonyms = index.synonyms(&rtxn).unwrap();
+        assert_eq!(synonyms, hashmap! { vec![S("cafe")] => vec![vec![S("coffee")]] });
+
+        let result = index.search(&rtxn).query("cafe").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+        let result = index.search(&rtxn).query("café").execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+    }
+
     #[test]
     fn setting_searchable_recomputes_other_settings() {
         let index = TempIndex::new();
@@ -1489,6 +1757,140 @@ mod tests {
         }
     }
 
+    #[test]
+    fn set_max_token_length() {
+        let index = TempIndex::new();
+
+        let blob = "abcdefghij".repeat(50);
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": blob },
+                { "id": 1, "text": "hello world" },
+            ]))
+            .unwrap();
+
+        // The blob is too long to be indexed at all by default.
+        let rtxn = index.read_txn().unwrap();
+        let result = index.search(&rtxn).query(&blob).execute().unwrap();
+        assert!(result.documents_ids.is_empty());
+        drop(rtxn);
+
+        index
+            .update_settings(|settings| {
+                settings.set_max_token_length(50);
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.max_token_length(&rtxn).unwrap(), Some(50));
+        assert!(index.word_docids.get(&rtxn, &blob[..50]).unwrap().is_some());
+        assert!(index.word_docids.get(&rtxn, &blob).unwrap().is_none());
+
+        // The query is truncated the same way and still finds the document.
+        let result = index.search(&rtxn).query(&blob).execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+    }
+
+    #[test]
+    fn set_zero_max_token_length() {
+        let index = TempIndex::new();
+
+        let error = index
+            .update_settings(|settings| {
+                settings.set_max_token_length(0);
+            })
+            .unwrap_err();
+        assert!(matches!(error, Error::UserError(UserError::InvalidMaxTokenLengthSetting)));
+    }
+
+    #[test]
+    fn set_max_token_length_truncates_synonyms() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_max_token_length(5);
+                settings.set_synonyms(hashmap! {
+                    S("television") => vec![S("screening device")],
+                });
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let synonyms = index.synonyms(&rtxn).unwrap();
+        assert_eq!(synonyms, hashmap! { vec![S("telev")] => vec![vec![S("scree"), S("devic")]] });
+    }
+
+    #[test]
+    fn update_max_token_length_truncates_stored_synonyms() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_synonyms(hashmap! {
+                    S("television") => vec![S("screening device")],
+                });
+            })
+            .unwrap();
+
+        // Only the limit changes, the synonyms already stored are truncated too.
+        index
+            .update_settings(|settings| {
+                settings.set_max_token_length(5);
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let synonyms = index.synonyms(&rtxn).unwrap();
+        assert_eq!(synonyms, hashmap! { vec![S("telev")] => vec![vec![S("scree"), S("devic")]] });
+        drop(rtxn);
+
+        // The original synonyms are restored when the limit is lifted.
+        index
+            .update_settings(|settings| {
+                settings.reset_max_token_length();
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let synonyms = index.synonyms(&rtxn).unwrap();
+        assert_eq!(
+            synonyms,
+            hashmap! { vec![S("television")] => vec![vec![S("screening"), S("device")]] }
+        );
+    }
+
+    #[test]
+    fn set_and_reset_max_document_id_length() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_max_document_id_length(1024);
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.max_document_id_length(&rtxn).unwrap(), Some(1024));
+        drop(rtxn);
+
+        let error = index
+            .update_settings(|settings| {
+                settings.set_max_document_id_length(0);
+            })
+            .unwrap_err();
+        assert!(matches!(error, Error::UserError(UserError::InvalidMaxDocumentIdLengthSetting)));
+
+        index
+            .update_settings(|settings| {
+                settings.reset_max_document_id_length();
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.max_document_id_length(&rtxn).unwrap(), None);
+    }
+
     #[test]
     fn test_correct_settings_init() {
         let index = TempIndex::new();
@@ -1510,12 +1912,19 @@ mod tests {
                     synonyms,
                     primary_key,
                     authorize_typos,
+                    exact_numbers,
                     min_word_len_two_typos,
                     min_word_len_one_typo,
                     exact_words,
                     exact_attributes,
+                    non_prefix_attributes,
+                    attributes_coverage,
                     max_values_per_facet,
                     pagination_max_total_hits,
+                    max_token_length,
+                    max_document_id_length,
+                    deletion_grace,
+                    min_exact_results,
                 } = settings;
                 assert!(matches!(searchable_fields, Setting::NotSet));
                 assert!(matches!(displayed_fields, Setting::NotSet));
@@ -1527,12 +1936,19 @@ mod tests {
                 assert!(matches!(synonyms, Setting::NotSet));
                 assert!(matches!(primary_key, Setting::NotSet));
                 assert!(matches!(authorize_typos, Setting::NotSet));
+                assert!(matches!(exact_numbers, Setting::NotSet));
                 assert!(matches!(min_word_len_two_typos, Setting::NotSet));
                 assert!(matches!(min_word_len_one_typo, Setting::NotSet));
                 assert!(matches!(exact_words, Setting::NotSet));
                 assert!(matches!(exact_attributes, Setting::NotSet));
+                assert!(matches!(non_prefix_attributes, Setting::NotSet));
+                assert!(matches!(attributes_coverage, Setting::NotSet));
                 assert!(matches!(max_values_per_facet, Setting::NotSet));
                 assert!(matches!(pagination_max_total_hits, Setting::NotSet));
+                assert!(matches!(max_token_length, Setting::NotSet));
+                assert!(matches!(max_document_id_length, Setting::NotSet));
+                assert!(matches!(deletion_grace, Setting::NotSet));
+                assert!(matches!(min_exact_results, Setting::NotSet));
             })
             .unwrap();
     }
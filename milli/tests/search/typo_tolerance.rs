@@ -1,6 +1,8 @@
 use std::collections::BTreeSet;
 
+use heed::types::Str;
 use heed::EnvOpenOptions;
+use milli::index::{db_name, main_key};
 use milli::update::{IndexDocuments, IndexDocumentsConfig, IndexerConfig, Settings};
 use milli::{Criterion, Index, Search, TermsMatchingStrategy};
 use serde_json::json;
@@ -206,3 +208,152 @@ fn test_disable_typo_on_attribute() {
     let result = search.execute().unwrap();
     assert_eq!(result.documents_ids.len(), 0);
 }
+
+#[test]
+fn test_typo_disabled_on_numbers() {
+    let tmp = tempdir().unwrap();
+    let mut options = EnvOpenOptions::new();
+    options.map_size(4096 * 100);
+    let index = Index::new(options, tmp.path()).unwrap();
+
+    let mut builder = milli::documents::DocumentsBatchBuilder::new(Vec::new());
+    let doc1 = json!({
+        "id": 1usize,
+        "data": "released in 2024",
+    });
+
+    let doc2 = json!({
+        "id": 2usize,
+        "data": "released in 2025",
+    });
+
+    builder.append_json_object(doc1.as_object().unwrap()).unwrap();
+    builder.append_json_object(doc2.as_object().unwrap()).unwrap();
+    let vector = builder.into_inner().unwrap();
+
+    let documents =
+        milli::documents::DocumentsBatchReader::from_reader(std::io::Cursor::new(vector)).unwrap();
+
+    let mut txn = index.write_txn().unwrap();
+    let config = IndexerConfig::default();
+    let indexing_config = IndexDocumentsConfig::default();
+    let builder =
+        IndexDocuments::new(&mut txn, &index, &config, indexing_config, |_| (), || false).unwrap();
+
+    let (builder, user_error) = builder.add_documents(documents).unwrap();
+    user_error.unwrap();
+    builder.execute().unwrap();
+
+    // allow typos on 4 letters words, like the years
+    let mut builder = Settings::new(&mut txn, &index, &config);
+    builder.set_min_word_len_one_typo(4);
+    builder.execute(|_| (), || false).unwrap();
+    txn.commit().unwrap();
+
+    // numbers are matched exactly by default
+    {
+        let txn = index.read_txn().unwrap();
+
+        let mut search = Search::new(&txn, &index);
+        search.query("2024");
+        search.limit(10);
+        search.authorize_typos(true);
+        search.terms_matching_strategy(TermsMatchingStrategy::default());
+
+        let result = search.execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+
+        // but the text words still tolerate typos
+        let mut search = Search::new(&txn, &index);
+        search.query("relaesed");
+        search.limit(10);
+        search.authorize_typos(true);
+        search.terms_matching_strategy(TermsMatchingStrategy::default());
+
+        let result = search.execute().unwrap();
+        assert_eq!(result.documents_ids.len(), 2);
+    }
+
+    let mut txn = index.write_txn().unwrap();
+
+    let mut builder = Settings::new(&mut txn, &index, &config);
+    builder.set_exact_numbers(false);
+    builder.execute(|_| (), || false).unwrap();
+
+    let mut search = Search::new(&txn, &index);
+    search.query("2024");
+    search.limit(10);
+    search.authorize_typos(true);
+    search.terms_matching_strategy(TermsMatchingStrategy::default());
+
+    let result = search.execute().unwrap();
+    assert_eq!(result.documents_ids.len(), 2);
+}
+
+#[test]
+fn test_typo_on_numbers_in_existing_index() {
+    let tmp = tempdir().unwrap();
+    let mut options = EnvOpenOptions::new();
+    options.map_size(4096 * 100);
+    options.max_dbs(19);
+    let index = Index::new(options.clone(), tmp.path()).unwrap();
+
+    let mut builder = milli::documents::DocumentsBatchBuilder::new(Vec::new());
+    let doc1 = json!({
+        "id": 1usize,
+        "data": "released in 2024",
+    });
+
+    let doc2 = json!({
+        "id": 2usize,
+        "data": "released in 2025",
+    });
+
+    builder.append_json_object(doc1.as_object().unwrap()).unwrap();
+    builder.append_json_object(doc2.as_object().unwrap()).unwrap();
+    let vector = builder.into_inner().unwrap();
+
+    let documents =
+        milli::documents::DocumentsBatchReader::from_reader(std::io::Cursor::new(vector)).unwrap();
+
+    let mut txn = index.write_txn().unwrap();
+    let config = IndexerConfig::default();
+    let indexing_config = IndexDocumentsConfig::default();
+    let builder =
+        IndexDocuments::new(&mut txn, &index, &config, indexing_config, |_| (), || false).unwrap();
+
+    let (builder, user_error) = builder.add_documents(documents).unwrap();
+    user_error.unwrap();
+    builder.execute().unwrap();
+
+    // allow typos on 4 letters words, like the years
+    let mut builder = Settings::new(&mut txn, &index, &config);
+    builder.set_min_word_len_one_typo(4);
+    builder.execute(|_| (), || false).unwrap();
+    txn.commit().unwrap();
+    index.prepare_for_closing().wait();
+
+    // remove the setting like in an index created before it existed
+    {
+        let env = options.open(tmp.path()).unwrap();
+        let main = env.open_poly_database(Some(db_name::MAIN)).unwrap().unwrap();
+        let mut txn = env.write_txn().unwrap();
+        main.delete::<_, Str>(&mut txn, main_key::EXACT_NUMBERS).unwrap();
+        txn.commit().unwrap();
+        env.prepare_for_closing().wait();
+    }
+
+    // the numbers are still matched with typos when the setting is unset
+    let index = Index::new(options, tmp.path()).unwrap();
+    let txn = index.read_txn().unwrap();
+    assert!(!index.exact_numbers(&txn).unwrap());
+
+    let mut search = Search::new(&txn, &index);
+    search.query("2024");
+    search.limit(10);
+    search.authorize_typos(true);
+    search.terms_matching_strategy(TermsMatchingStrategy::default());
+
+    let result = search.execute().unwrap();
+    assert_eq!(result.documents_ids.len(), 2);
+}